@@ -0,0 +1,77 @@
+// A pattern that lives outside of the raytracer crate, put on a sphere
+//
+//     cargo run --release --example custom_pattern
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use raytracer::camera::Camera;
+use raytracer::canvas::BitDepth;
+use raytracer::color::Color;
+use raytracer::generics::Drawables;
+use raytracer::lights::Lights;
+use raytracer::material::Material;
+use raytracer::matrix::Matrix4;
+use raytracer::patterns::{Pattern, Patterns};
+use raytracer::point_light::PointLight;
+use raytracer::sphere::Sphere;
+use raytracer::tuple::Tuple;
+use raytracer::world::World;
+
+// Dots on a grid, one unit apart
+struct Polka {
+    dot: Color,
+    background: Color,
+    radius: f64,
+    transform: Matrix4,
+}
+
+impl Pattern for Polka {
+    fn color_at(&self, point: Tuple) -> Color {
+        let dx = point.x - point.x.round();
+        let dy = point.y - point.y.round();
+        let dz = point.z - point.z.round();
+
+        if dx * dx + dy * dy + dz * dz < self.radius * self.radius {
+            self.dot
+        } else {
+            self.background
+        }
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) { self.transform = transform; }
+    fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform.inverse() }
+    fn get_a(&self) -> Color { self.dot }
+    fn get_b(&self) -> Color { self.background }
+}
+
+fn main() {
+    let polka = Polka {
+        dot: Color::new(1., 1., 1.),
+        background: Color::new(0.8, 0.1, 0.2),
+        radius: 0.35,
+        transform: Matrix4::new_scaling(0.25, 0.25, 0.25),
+    };
+
+    let mut sphere = Sphere::new();
+    sphere.shape.material = Material {
+        pattern: Some(Patterns::new_custom(Arc::new(polka))),
+        ..Default::default()
+    };
+
+    let world = World {
+        lights: vec![Lights::Point(PointLight::new(Tuple::new_point(-10., 10., -10.), Color::new(1., 1., 1.)).unwrap())],
+        objects: vec![Drawables::Sphere(sphere)],
+    };
+
+    let mut camera = Camera::new(200, 200, PI / 3.);
+    camera.transform = Matrix4::new_view_transform(
+        Tuple::new_point(0., 0., -4.),
+        Tuple::new_point(0., 0., 0.),
+        Tuple::new_vector(0., 1., 0.),
+    );
+
+    let canvas = camera.render(&world).expect("could not render");
+    canvas.save("custom_pattern.png", BitDepth::Eight).expect("could not save");
+}
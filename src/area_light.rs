@@ -0,0 +1,87 @@
+use super::tuple::Tuple;
+use super::color::Color;
+use super::random::Rng;
+
+// A rectangle of light, made up of u_steps by v_steps cells with a light in
+// each of them. Points that only see some of the cells end up partly in
+// shadow, which is what makes the shadows soft.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AreaLight {
+    pub corner: Tuple,
+    // The size of one cell along each edge
+    pub u_vec: Tuple,
+    pub u_steps: usize,
+    pub v_vec: Tuple,
+    pub v_steps: usize,
+    pub intensity: Color,
+    // Moves the light in each cell to a random spot instead of its centre,
+    // which trades banding in the shadows for noise
+    pub jitter: bool,
+}
+
+#[allow(dead_code)]
+impl AreaLight {
+    // full_u_vec and full_v_vec are the two edges of the rectangle
+    pub fn new(
+        corner: Tuple,
+        full_u_vec: Tuple,
+        u_steps: usize,
+        full_v_vec: Tuple,
+        v_steps: usize,
+        intensity: Color,
+    ) -> Result<Self, &'static str> {
+        if corner.is_vector() {
+            return Err("Corner can't be a vector");
+        }
+        if full_u_vec.is_point() || full_v_vec.is_point() {
+            return Err("The edges of an area light have to be vectors");
+        }
+        if u_steps == 0 || v_steps == 0 {
+            return Err("An area light needs at least one step along each edge");
+        }
+
+        Ok(Self {
+            corner,
+            u_vec: full_u_vec / u_steps as f64,
+            u_steps,
+            v_vec: full_v_vec / v_steps as f64,
+            v_steps,
+            intensity,
+            jitter: false,
+        })
+    }
+
+    pub fn samples(&self) -> usize {
+        self.u_steps * self.v_steps
+    }
+
+    // The middle of the rectangle
+    pub fn position(&self) -> Tuple {
+        self.corner + self.u_vec * (self.u_steps as f64 / 2.) + self.v_vec * (self.v_steps as f64 / 2.)
+    }
+
+    // The offset says where inside the cell, from 0 to 1
+    pub fn point_on_light(&self, u: usize, v: usize, offset: (f64, f64)) -> Tuple {
+        self.corner + self.u_vec * (u as f64 + offset.0) + self.v_vec * (v as f64 + offset.1)
+    }
+
+    // Every light in the rectangle, as seen from the point. Jittered lights
+    // are seeded from the point, so the same point always sees the same ones.
+    pub fn points_on_light(&self, point: Tuple) -> Vec<Tuple> {
+        let seed = point.x.to_bits() ^ point.y.to_bits().rotate_left(21) ^ point.z.to_bits().rotate_left(42);
+        let mut rng = Rng::new(seed);
+
+        let mut points = Vec::with_capacity(self.samples());
+        for v in 0..self.v_steps {
+            for u in 0..self.u_steps {
+                let offset = if self.jitter {
+                    (rng.next_f64(), rng.next_f64())
+                } else {
+                    (0.5, 0.5)
+                };
+                points.push(self.point_on_light(u, v, offset));
+            }
+        }
+        points
+    }
+}
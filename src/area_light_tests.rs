@@ -0,0 +1,82 @@
+#[cfg(test)]
+
+mod area_light_tests {
+    use crate::area_light::*;
+    use crate::color::Color;
+    use crate::tuple::Tuple;
+
+    fn light() -> AreaLight {
+        AreaLight::new(
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(2., 0., 0.),
+            4,
+            Tuple::new_vector(0., 0., 1.),
+            2,
+            Color::new(1., 1., 1.),
+        ).unwrap()
+    }
+
+    #[test]
+    fn create_area_light() {
+        let light = light();
+
+        assert_eq!(light.corner, Tuple::new_point(0., 0., 0.));
+        assert_eq!(light.u_vec, Tuple::new_vector(0.5, 0., 0.));
+        assert_eq!(light.u_steps, 4);
+        assert_eq!(light.v_vec, Tuple::new_vector(0., 0., 0.5));
+        assert_eq!(light.v_steps, 2);
+        assert_eq!(light.samples(), 8);
+        assert_eq!(light.position(), Tuple::new_point(1., 0., 0.5));
+        assert!(!light.jitter);
+    }
+
+    #[test]
+    fn bad_area_lights() {
+        let corner = Tuple::new_point(0., 0., 0.);
+        let edge = Tuple::new_vector(1., 0., 0.);
+        let white = Color::new(1., 1., 1.);
+
+        assert!(AreaLight::new(edge, edge, 1, edge, 1, white).is_err());
+        assert!(AreaLight::new(corner, corner, 1, edge, 1, white).is_err());
+        assert!(AreaLight::new(corner, edge, 0, edge, 1, white).is_err());
+    }
+
+    #[test]
+    fn point_on_area_light() {
+        let light = light();
+        let cases = [
+            (0, 0, Tuple::new_point(0.25, 0., 0.25)),
+            (1, 0, Tuple::new_point(0.75, 0., 0.25)),
+            (0, 1, Tuple::new_point(0.25, 0., 0.75)),
+            (2, 0, Tuple::new_point(1.25, 0., 0.25)),
+            (3, 1, Tuple::new_point(1.75, 0., 0.75)),
+        ];
+
+        for (u, v, point) in cases.iter() {
+            assert_eq!(light.point_on_light(*u, *v, (0.5, 0.5)), *point);
+        }
+    }
+
+    #[test]
+    fn points_on_area_light() {
+        let mut light = light();
+        let point = Tuple::new_point(0., 5., 0.);
+
+        let points = light.points_on_light(point);
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0], Tuple::new_point(0.25, 0., 0.25));
+        assert_eq!(points[7], Tuple::new_point(1.75, 0., 0.75));
+
+        // Jittered points stay inside of their cell, and the same
+        // point always sees them in the same place
+        light.jitter = true;
+        let jittered = light.points_on_light(point);
+        assert_eq!(jittered, light.points_on_light(point));
+        assert_ne!(jittered, points);
+        for (i, p) in jittered.iter().enumerate() {
+            let (u, v) = ((i % 4) as f64, (i / 4) as f64);
+            assert!(p.x >= u * 0.5 && p.x < (u + 1.) * 0.5);
+            assert!(p.z >= v * 0.5 && p.z < (v + 1.) * 0.5);
+        }
+    }
+}
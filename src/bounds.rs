@@ -0,0 +1,154 @@
+use super::utils::EPSILON;
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::ray::Ray;
+
+// An axis aligned box around a drawable, used to skip testing
+// drawables that a ray can't possibly hit
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    pub min: Tuple,
+    pub max: Tuple,
+}
+
+#[allow(dead_code)]
+impl BoundingBox {
+    pub fn new(min: Tuple, max: Tuple) -> Self {
+        Self { min, max }
+    }
+
+    // A box that contains nothing, and grows to fit whatever is added to it
+    pub fn empty() -> Self {
+        Self {
+            min: Tuple::new_point(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            max: Tuple::new_point(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        }
+    }
+
+    pub fn infinite() -> Self {
+        Self {
+            min: Tuple::new_point(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+            max: Tuple::new_point(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.min.x.is_finite() && self.min.y.is_finite() && self.min.z.is_finite() &&
+        self.max.x.is_finite() && self.max.y.is_finite() && self.max.z.is_finite()
+    }
+
+    pub fn add_point(&mut self, point: Tuple) {
+        self.min = Tuple::new_point(self.min.x.min(point.x), self.min.y.min(point.y), self.min.z.min(point.z));
+        self.max = Tuple::new_point(self.max.x.max(point.x), self.max.y.max(point.y), self.max.z.max(point.z));
+    }
+
+    pub fn merge(&mut self, other: BoundingBox) {
+        if other.is_empty() {
+            return;
+        }
+        self.add_point(other.min);
+        self.add_point(other.max);
+    }
+
+    pub fn contains_point(&self, point: Tuple) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+        self.min.y <= point.y && point.y <= self.max.y &&
+        self.min.z <= point.z && point.z <= self.max.z
+    }
+
+    pub fn contains_box(&self, other: BoundingBox) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    // The box that fits around all eight corners of this box once transformed
+    pub fn transform(&self, matrix: Matrix4) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+
+        // Infinity times zero in the matrix would give us NaN,
+        // so anything unbounded just stays unbounded
+        if !self.is_finite() {
+            return Self::infinite();
+        }
+
+        let corners = [
+            self.min,
+            Tuple::new_point(self.min.x, self.min.y, self.max.z),
+            Tuple::new_point(self.min.x, self.max.y, self.min.z),
+            Tuple::new_point(self.min.x, self.max.y, self.max.z),
+            Tuple::new_point(self.max.x, self.min.y, self.min.z),
+            Tuple::new_point(self.max.x, self.min.y, self.max.z),
+            Tuple::new_point(self.max.x, self.max.y, self.min.z),
+            self.max,
+        ];
+
+        let mut result = Self::empty();
+        for corner in corners.iter() {
+            result.add_point(matrix * *corner);
+        }
+        result
+    }
+
+    fn check_axis(origin: f64, direction: f64, min: f64, max: f64) -> (f64, f64) {
+        let t_min_numerator = min - origin;
+        let t_max_numerator = max - origin;
+
+        let (t_min, t_max) = if direction.abs() >= EPSILON {
+            (t_min_numerator / direction, t_max_numerator / direction)
+        } else {
+            (t_min_numerator * f64::INFINITY, t_max_numerator * f64::INFINITY)
+        };
+
+        if t_min > t_max {
+            return (t_max, t_min);
+        }
+        (t_min, t_max)
+    }
+
+    pub fn intersects(&self, ray: Ray) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let (x_t_min, x_t_max) = Self::check_axis(ray.origin.x, ray.direction.x, self.min.x, self.max.x);
+        let (y_t_min, y_t_max) = Self::check_axis(ray.origin.y, ray.direction.y, self.min.y, self.max.y);
+        let (z_t_min, z_t_max) = Self::check_axis(ray.origin.z, ray.direction.z, self.min.z, self.max.z);
+
+        let t_min = x_t_min.max(y_t_min).max(z_t_min);
+        let t_max = x_t_max.min(y_t_max).min(z_t_max);
+
+        t_min <= t_max
+    }
+
+    // Cuts the box in half across its longest axis
+    pub fn split(&self) -> (Self, Self) {
+        let dx = self.max.x - self.min.x;
+        let dy = self.max.y - self.min.y;
+        let dz = self.max.z - self.min.z;
+        let greatest = dx.max(dy).max(dz);
+
+        let (mut x0, mut y0, mut z0) = (self.min.x, self.min.y, self.min.z);
+        let (mut x1, mut y1, mut z1) = (self.max.x, self.max.y, self.max.z);
+
+        if greatest == dx {
+            x0 += dx / 2.;
+            x1 = x0;
+        } else if greatest == dy {
+            y0 += dy / 2.;
+            y1 = y0;
+        } else {
+            z0 += dz / 2.;
+            z1 = z0;
+        }
+
+        let mid_min = Tuple::new_point(x0, y0, z0);
+        let mid_max = Tuple::new_point(x1, y1, z1);
+
+        (Self::new(self.min, mid_max), Self::new(mid_min, self.max))
+    }
+}
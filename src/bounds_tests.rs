@@ -0,0 +1,192 @@
+#[cfg(test)]
+
+mod bounds_tests {
+    use std::f64::consts::PI;
+    use crate::bounds::*;
+    use crate::tuple::Tuple;
+    use crate::matrix::Matrix4;
+    use crate::ray::Ray;
+    use crate::sphere::Sphere;
+    use crate::plane::Plane;
+    use crate::cylinder::Cylinder;
+    use crate::cone::Cone;
+    use crate::triangle::Triangle;
+    use crate::group::Group;
+    use crate::csg::{Csg, CsgOperation};
+    use crate::generics::{Drawable, Drawables};
+
+    #[test]
+    fn empty_bounding_box() {
+        let b = BoundingBox::empty();
+        assert!(b.is_empty());
+        assert_eq!((b.min.x, b.min.y, b.min.z), (f64::INFINITY, f64::INFINITY, f64::INFINITY));
+        assert_eq!((b.max.x, b.max.y, b.max.z), (f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn add_points_to_box() {
+        let mut b = BoundingBox::empty();
+        b.add_point(Tuple::new_point(-5., 2., 0.));
+        b.add_point(Tuple::new_point(7., 0., -3.));
+
+        assert_eq!(b.min, Tuple::new_point(-5., 0., -3.));
+        assert_eq!(b.max, Tuple::new_point(7., 2., 0.));
+    }
+
+    #[test]
+    fn merge_boxes() {
+        let mut b1 = BoundingBox::new(Tuple::new_point(-5., -2., 0.), Tuple::new_point(7., 4., 4.));
+        let b2 = BoundingBox::new(Tuple::new_point(8., -7., -2.), Tuple::new_point(14., 2., 8.));
+        b1.merge(b2);
+
+        assert_eq!(b1.min, Tuple::new_point(-5., -7., -2.));
+        assert_eq!(b1.max, Tuple::new_point(14., 4., 8.));
+    }
+
+    #[test]
+    fn box_contains_point() {
+        let b = BoundingBox::new(Tuple::new_point(5., -2., 0.), Tuple::new_point(11., 4., 7.));
+        let cases = [
+            (Tuple::new_point(5., -2., 0.), true),
+            (Tuple::new_point(11., 4., 7.), true),
+            (Tuple::new_point(8., 1., 3.), true),
+            (Tuple::new_point(3., 0., 3.), false),
+            (Tuple::new_point(8., -4., 3.), false),
+            (Tuple::new_point(8., 1., -1.), false),
+            (Tuple::new_point(13., 1., 3.), false),
+            (Tuple::new_point(8., 5., 3.), false),
+            (Tuple::new_point(8., 1., 8.), false),
+        ];
+
+        for (point, result) in cases.iter() {
+            assert_eq!(b.contains_point(*point), *result);
+        }
+    }
+
+    #[test]
+    fn box_contains_box() {
+        let b = BoundingBox::new(Tuple::new_point(5., -2., 0.), Tuple::new_point(11., 4., 7.));
+        let cases = [
+            (Tuple::new_point(5., -2., 0.), Tuple::new_point(11., 4., 7.), true),
+            (Tuple::new_point(6., -1., 1.), Tuple::new_point(10., 3., 6.), true),
+            (Tuple::new_point(4., -3., -1.), Tuple::new_point(10., 3., 6.), false),
+            (Tuple::new_point(6., -1., 1.), Tuple::new_point(12., 5., 8.), false),
+        ];
+
+        for (min, max, result) in cases.iter() {
+            assert_eq!(b.contains_box(BoundingBox::new(*min, *max)), *result);
+        }
+    }
+
+    #[test]
+    fn transform_box() {
+        let b = BoundingBox::new(Tuple::new_point(-1., -1., -1.), Tuple::new_point(1., 1., 1.));
+        let m = Matrix4::new_rotation_x(PI / 4.) * Matrix4::new_rotation_y(PI / 4.);
+        let b2 = b.transform(m);
+
+        assert_eq!(b2.min, Tuple::new_point(-2_f64.sqrt(), -1.70711, -1.70711));
+        assert_eq!(b2.max, Tuple::new_point(2_f64.sqrt(), 1.70711, 1.70711));
+    }
+
+    #[test]
+    fn transform_infinite_box() {
+        let b = Drawables::Plane(Plane::new()).bounds();
+        let b2 = b.transform(Matrix4::new_rotation_x(PI / 2.));
+
+        assert!(!b2.is_finite());
+        assert!(!b2.min.x.is_nan());
+    }
+
+    #[test]
+    fn ray_intersects_box_at_origin() {
+        let b = BoundingBox::new(Tuple::new_point(-1., -1., -1.), Tuple::new_point(1., 1., 1.));
+        let cases = [
+            (Tuple::new_point(5., 0.5, 0.), Tuple::new_vector(-1., 0., 0.), true),
+            (Tuple::new_point(-5., 0.5, 0.), Tuple::new_vector(1., 0., 0.), true),
+            (Tuple::new_point(0.5, 5., 0.), Tuple::new_vector(0., -1., 0.), true),
+            (Tuple::new_point(0.5, 0., -5.), Tuple::new_vector(0., 0., 1.), true),
+            (Tuple::new_point(0., 0.5, 0.), Tuple::new_vector(0., 0., 1.), true),
+            (Tuple::new_point(-2., 0., 0.), Tuple::new_vector(2., 4., 6.), false),
+            (Tuple::new_point(0., -2., 0.), Tuple::new_vector(6., 2., 4.), false),
+            (Tuple::new_point(2., 0., 2.), Tuple::new_vector(0., 0., -1.), false),
+            (Tuple::new_point(2., 2., 0.), Tuple::new_vector(-1., 0., 0.), false),
+        ];
+
+        for (origin, direction, result) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            assert_eq!(b.intersects(r), *result);
+        }
+    }
+
+    #[test]
+    fn ray_intersects_non_cubic_box() {
+        let b = BoundingBox::new(Tuple::new_point(5., -2., 0.), Tuple::new_point(11., 4., 7.));
+        let cases = [
+            (Tuple::new_point(15., 1., 2.), Tuple::new_vector(-1., 0., 0.), true),
+            (Tuple::new_point(8., 2., 12.), Tuple::new_vector(0., 0., -1.), true),
+            (Tuple::new_point(9., -1., -8.), Tuple::new_vector(2., 4., 6.), false),
+            (Tuple::new_point(8., 6., -1.), Tuple::new_vector(0., -1., 0.), false),
+        ];
+
+        for (origin, direction, result) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            assert_eq!(b.intersects(r), *result);
+        }
+    }
+
+    #[test]
+    fn split_box() {
+        let b = BoundingBox::new(Tuple::new_point(-1., -4., -5.), Tuple::new_point(9., 6., 5.));
+        let (left, right) = b.split();
+
+        assert_eq!(left.min, Tuple::new_point(-1., -4., -5.));
+        assert_eq!(left.max, Tuple::new_point(4., 6., 5.));
+        assert_eq!(right.min, Tuple::new_point(4., -4., -5.));
+        assert_eq!(right.max, Tuple::new_point(9., 6., 5.));
+    }
+
+    #[test]
+    fn bounds_of_primitives() {
+        let s = Sphere::new();
+        assert_eq!(s.bounds(), BoundingBox::new(Tuple::new_point(-1., -1., -1.), Tuple::new_point(1., 1., 1.)));
+
+        let mut c = Cylinder::new();
+        c.minimum = -5.;
+        c.maximum = 3.;
+        assert_eq!(c.bounds(), BoundingBox::new(Tuple::new_point(-1., -5., -1.), Tuple::new_point(1., 3., 1.)));
+
+        let mut c = Cone::new();
+        c.minimum = -5.;
+        c.maximum = 3.;
+        assert_eq!(c.bounds(), BoundingBox::new(Tuple::new_point(-5., -5., -5.), Tuple::new_point(5., 3., 5.)));
+
+        let t = Triangle::new(
+            Tuple::new_point(-3., 7., 2.),
+            Tuple::new_point(6., 2., -4.),
+            Tuple::new_point(2., -1., -1.),
+        );
+        assert_eq!(t.bounds(), BoundingBox::new(Tuple::new_point(-3., -1., -4.), Tuple::new_point(6., 7., 2.)));
+    }
+
+    #[test]
+    fn bounds_of_group_and_csg() {
+        let s = Drawables::Sphere(Sphere::new_with_transform(
+            Matrix4::new_translation(2., 5., -3.) * Matrix4::new_scaling(2., 2., 2.)
+        ));
+        let mut c = Cylinder::new_with_transform(
+            Matrix4::new_translation(-4., -1., 4.) * Matrix4::new_scaling(0.5, 1., 0.5)
+        );
+        c.minimum = -2.;
+        c.maximum = 2.;
+        let c = Drawables::Cylinder(c);
+
+        let mut g = Group::new();
+        g.add_child(s.clone());
+        g.add_child(c.clone());
+        let expected = BoundingBox::new(Tuple::new_point(-4.5, -3., -5.), Tuple::new_point(4., 7., 4.5));
+        assert_eq!(g.bounds(), expected);
+
+        let csg = Csg::new(CsgOperation::Difference, s, c);
+        assert_eq!(csg.bounds(), expected);
+    }
+}
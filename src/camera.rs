@@ -4,7 +4,34 @@ use super::ray::Ray;
 use super::tuple::Tuple;
 use super::world::World;
 use super::canvas::Canvas;
+use super::color::Color;
+use super::random::Rng;
 use super::utils::RECURSION_DEPTH;
+use std::f64::consts::PI;
+
+// Where inside a pixel the rays for it are shot through
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Sampling {
+    // Evenly spaced on a grid, which is just the centre for one sample
+    Grid,
+    // One random point inside each cell of a grid
+    Jittered,
+    // Anywhere inside the pixel
+    Random,
+}
+
+pub const ADAPTIVE_DEPTH: usize = 2;
+
+// Adaptive anti-aliasing only spends extra rays on pixels that
+// look different from their neighbours, see render_adaptive
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Adaptive {
+    // How much any color channel can differ between neighbours
+    // before they get refined
+    pub threshold: f64,
+    // How many times a refined pixel can be split into quarters
+    pub max_depth: usize,
+}
 
 #[derive(Debug, Copy, Clone)]
 pub struct Camera {
@@ -15,12 +42,45 @@ pub struct Camera {
     pub half_width: f64,
     pub half_height: f64,
     pub transform: Matrix4,
+    // How many times reflected and refracted rays are followed
+    pub recursion_depth: usize,
+    // Rays per pixel, averaged together. Grid and Jittered sampling round
+    // this up to the next square number.
+    pub samples: usize,
+    pub sampling: Sampling,
+    // Replaces samples and sampling when set
+    pub adaptive: Option<Adaptive>,
+    // Width of the lens. Anything that isn't focal_distance away
+    // gets blurred, more so with a wider lens. 0 keeps everything sharp.
+    pub aperture: f64,
+    pub focal_distance: f64,
 }
 
 #[allow(dead_code)]
 impl Camera {
     pub fn new(h_size: usize, v_size: usize, field_of_view: f64) -> Self {
-        let half_view = (field_of_view / 2.0).tan();
+        let mut camera = Self {
+            h_size,
+            v_size,
+            field_of_view,
+            pixel_size: 0.,
+            half_width: 0.,
+            half_height: 0.,
+            transform: Matrix4::new_identity(),
+            recursion_depth: RECURSION_DEPTH,
+            samples: 1,
+            sampling: Sampling::Grid,
+            adaptive: None,
+            aperture: 0.,
+            focal_distance: 1.,
+        };
+        camera.resize(h_size, v_size);
+        camera
+    }
+
+    // Changes the size of the picture, but keeps everything else
+    pub fn resize(&mut self, h_size: usize, v_size: usize) {
+        let half_view = (self.field_of_view / 2.0).tan();
         let aspect = (h_size as f64) / (v_size as f64);
 
         let (half_width, half_height) = if aspect >= 1. {
@@ -29,46 +89,224 @@ impl Camera {
             (half_view * aspect, half_view)
         };
 
-        let pixel_size = (half_width * 2.) / h_size as f64;
+        self.h_size = h_size;
+        self.v_size = v_size;
+        self.half_width = half_width;
+        self.half_height = half_height;
+        self.pixel_size = (half_width * 2.) / h_size as f64;
+    }
 
-        Self {
-            h_size,
-            v_size,
-            field_of_view,
-            pixel_size,
-            half_width,
-            half_height,
-            transform: Matrix4::new_identity(),
+    // Shoots through the centre of the pixel
+    pub fn ray_for_pixel(self, px: usize, py: usize) -> Result<Ray, &'static str> {
+        self.ray_for_pixel_offset(px, py, 0.5, 0.5)
+    }
+
+    // The offsets say where inside the pixel to shoot through,
+    // from 0 to 1 starting at the top left corner
+    pub fn ray_for_pixel_offset(self, px: usize, py: usize, x_offset: f64, y_offset: f64) -> Result<Ray, &'static str> {
+        self.ray_for_pixel_on_lens(px, py, x_offset, y_offset, (0., 0.))
+    }
+
+    // Like ray_for_pixel_offset, but starts from a point on the lens, see lens_point
+    pub fn ray_for_pixel_on_lens(self, px: usize, py: usize, x_offset: f64, y_offset: f64, lens: (f64, f64)) -> Result<Ray, &'static str> {
+        match self.transform.inverse() {
+            Some(transform_inverse) => {
+                self.ray_through(transform_inverse, px as f64 + x_offset, py as f64 + y_offset, lens)
+            },
+            None => Err("Could not create a ray"),
         }
     }
 
-    pub fn ray_for_pixel(self, px: usize, py: usize) -> Result<Ray, &'static str> {
-        let x_offset = (px as f64 + 0.5) * self.pixel_size;
-        let y_offset = (py as f64 + 0.5) * self.pixel_size;
+    // A random point on the lens, relative to its centre.
+    // Always the centre when there is no aperture.
+    pub fn lens_point(self, rng: &mut Rng) -> (f64, f64) {
+        if self.aperture <= 0. {
+            return (0., 0.);
+        }
+
+        // The square root spreads the points evenly over the disc
+        // instead of bunching them up in the middle
+        let radius = self.aperture / 2. * rng.next_f64().sqrt();
+        let angle = 2. * PI * rng.next_f64();
+        (radius * angle.cos(), radius * angle.sin())
+    }
+
+    // x and y are in pixels, but don't have to be whole.
+    // Every ray through the same pixel meets at the focal distance,
+    // wherever on the lens it starts.
+    fn ray_through(self, transform_inverse: Matrix4, x: f64, y: f64, lens: (f64, f64)) -> Result<Ray, &'static str> {
+        let world_x = self.half_width - x * self.pixel_size;
+        let world_y = self.half_height - y * self.pixel_size;
+
+        let distance = self.focal_distance;
+        let focus = transform_inverse * Tuple::new_point(world_x * distance, world_y * distance, -distance);
+        let origin = transform_inverse * Tuple::new_point(lens.0, lens.1, 0.);
+        let direction = (focus - origin).normalize();
+        Ray::new(origin, direction)
+    }
+
+    // Where to shoot the rays for one pixel, see ray_for_pixel_offset
+    pub fn sample_offsets(self, rng: &mut Rng) -> Vec<(f64, f64)> {
+        let samples = self.samples.max(1);
+        if self.sampling == Sampling::Random {
+            return (0..samples).map(|_| (rng.next_f64(), rng.next_f64())).collect();
+        }
+
+        let cells = (samples as f64).sqrt().ceil() as usize;
+        let mut offsets = vec![];
+        for row in 0..cells {
+            for column in 0..cells {
+                let (dx, dy) = match self.sampling {
+                    Sampling::Jittered => (rng.next_f64(), rng.next_f64()),
+                    _ => (0.5, 0.5),
+                };
+                offsets.push(((column as f64 + dx) / cells as f64, (row as f64 + dy) / cells as f64));
+            }
+        }
+        offsets
+    }
 
-        let world_x = self.half_width - x_offset;
-        let world_y = self.half_height - y_offset;
+    fn color_at_pixel(self, world: &World, transform_inverse: Matrix4, px: usize, py: usize) -> Color {
+        let mut rng = Rng::new((py * self.h_size + px) as u64);
+        let offsets = self.sample_offsets(&mut rng);
 
-        if let Some(transform_inverse) = self.transform.inverse() {
-            let pixel = transform_inverse * Tuple::new_point(world_x, world_y, -1.);
-            let origin = transform_inverse * Tuple::new_point(0., 0., 0.);
-            let direction = (pixel - origin).normalize();
-            return Ray::new(origin, direction);
+        let mut color = Color::new(0., 0., 0.);
+        for (x_offset, y_offset) in offsets.iter() {
+            let lens = self.lens_point(&mut rng);
+            let ray = self.ray_through(transform_inverse, px as f64 + x_offset, py as f64 + y_offset, lens).unwrap();
+            color = color + world.color_at(ray, self.recursion_depth);
         }
-        Err("Could not create a ray")
+
+        color / offsets.len() as f64
     }
 
     pub fn render(self, world: &World) -> Result<Canvas, &'static str> {
+        if let Some(adaptive) = self.adaptive {
+            return Ok(self.render_adaptive(world, adaptive)?.0);
+        }
+
+        let transform_inverse = match self.transform.inverse() {
+            Some(transform_inverse) => transform_inverse,
+            None => return Err("The camera transform can't be inverted"),
+        };
+
         let mut canvas = Canvas::new(self.h_size, self.v_size);
 
         canvas.data.par_iter_mut().flatten().for_each(|e| {
             let x = e.r;
             let y = e.g;
-            let ray = self.ray_for_pixel(x as usize, y as usize).unwrap();
-            let color = world.color_at(ray, RECURSION_DEPTH);
-            *e = color;
+            *e = self.color_at_pixel(world, transform_inverse, x as usize, y as usize);
         });
 
         Ok(canvas)
     }
+
+    // Renders one ray per pixel first, then goes back and refines the pixels
+    // that differ too much from a neighbour. Also returns a map of the refined
+    // pixels, brighter the more times they were split.
+    pub fn render_adaptive(self, world: &World, adaptive: Adaptive) -> Result<(Canvas, Canvas), &'static str> {
+        let transform_inverse = match self.transform.inverse() {
+            Some(transform_inverse) => transform_inverse,
+            None => return Err("The camera transform can't be inverted"),
+        };
+
+        let mut canvas = Canvas::new(self.h_size, self.v_size);
+        canvas.data.par_iter_mut().flatten().for_each(|e| {
+            let (x, y) = (e.r, e.g);
+            let mut rng = Rng::new((y as usize * self.h_size + x as usize) as u64);
+            let lens = self.lens_point(&mut rng);
+            let ray = self.ray_through(transform_inverse, x + 0.5, y + 0.5, lens).unwrap();
+            *e = world.color_at(ray, self.recursion_depth);
+        });
+
+        let mut refine = vec![vec![false; self.h_size]; self.v_size];
+        for y in 0..self.v_size {
+            for x in 0..self.h_size {
+                let color = canvas.data[y][x];
+                if x + 1 < self.h_size && Self::differs(color, canvas.data[y][x + 1], adaptive.threshold) {
+                    refine[y][x] = true;
+                    refine[y][x + 1] = true;
+                }
+                if y + 1 < self.v_size && Self::differs(color, canvas.data[y + 1][x], adaptive.threshold) {
+                    refine[y][x] = true;
+                    refine[y + 1][x] = true;
+                }
+            }
+        }
+
+        let mut depths = vec![vec![0; self.h_size]; self.v_size];
+        canvas.data.par_iter_mut().zip(depths.par_iter_mut()).enumerate().for_each(|(y, (row, depth_row))| {
+            for x in 0..row.len() {
+                if refine[y][x] {
+                    let mut rng = Rng::new((y * self.h_size + x) as u64);
+                    let area = (x as f64, y as f64, 1.);
+                    let (color, depth) = self.refine_area(world, transform_inverse, adaptive, &mut rng, area, 1);
+                    row[x] = color;
+                    depth_row[x] = depth;
+                }
+            }
+        });
+
+        let mut refinement_map = Canvas::new(self.h_size, self.v_size);
+        for (y, depth_row) in depths.iter().enumerate() {
+            for (x, depth) in depth_row.iter().enumerate() {
+                let shade = *depth as f64 / adaptive.max_depth.max(1) as f64;
+                refinement_map.data[y][x] = Color::new(shade, shade, shade);
+            }
+        }
+
+        Ok((canvas, refinement_map))
+    }
+
+    fn differs(a: Color, b: Color, threshold: f64) -> bool {
+        (a.r() - b.r()).abs() > threshold ||
+        (a.g() - b.g()).abs() > threshold ||
+        (a.b() - b.b()).abs() > threshold
+    }
+
+    // Samples the corners and the centre of a square part of a pixel,
+    // given as its top left corner and size, and splits it into quarters
+    // if they don't agree.
+    // Returns the color and how deep the splitting went.
+    fn refine_area(
+        self,
+        world: &World,
+        transform_inverse: Matrix4,
+        adaptive: Adaptive,
+        rng: &mut Rng,
+        area: (f64, f64, f64),
+        depth: usize,
+    ) -> (Color, usize) {
+        let (x, y, size) = area;
+        let mut color_at = |x: f64, y: f64| {
+            let lens = self.lens_point(rng);
+            let ray = self.ray_through(transform_inverse, x, y, lens).unwrap();
+            world.color_at(ray, self.recursion_depth)
+        };
+
+        let half = size / 2.;
+        let centre = color_at(x + half, y + half);
+        let corners = [
+            color_at(x, y),
+            color_at(x + size, y),
+            color_at(x, y + size),
+            color_at(x + size, y + size),
+        ];
+
+        let agree = corners.iter().all(|c| !Self::differs(*c, centre, adaptive.threshold));
+        if agree || depth >= adaptive.max_depth {
+            let sum = corners.iter().fold(centre, |sum, c| sum + *c);
+            return (sum / 5., depth);
+        }
+
+        let mut color = Color::new(0., 0., 0.);
+        let mut deepest = depth;
+        for (dx, dy) in [(0., 0.), (half, 0.), (0., half), (half, half)].iter() {
+            let (c, d) = self.refine_area(world, transform_inverse, adaptive, rng, (x + dx, y + dy, half), depth + 1);
+            color = color + c;
+            deepest = deepest.max(d);
+        }
+
+        (color / 4., deepest)
+    }
 }
@@ -2,11 +2,16 @@
 
 mod camera_tests {
     use crate::utils::is_equal;
-    use crate::camera::Camera;
+    use crate::camera::{Adaptive, Camera, Sampling};
+    use crate::random::Rng;
+    use crate::sphere::Sphere;
+    use crate::generics::Drawables;
     use crate::matrix::Matrix4;
     use crate::tuple::Tuple;
     use crate::world::World;
     use crate::color::Color;
+    use crate::canvas::Canvas;
+    use crate::material::Material;
 
     use std::f64::consts::PI;
 
@@ -75,4 +80,234 @@ mod camera_tests {
 
         assert_eq!(canvas.get_color(5, 5).unwrap(), Color::new(0.38066, 0.47583, 0.2855));
     }
+
+    #[test]
+    fn render_with_broken_camera_transform() {
+        let w: World = Default::default();
+        let mut c = Camera::new(11, 11, PI / 2.);
+        c.transform = Matrix4::new_scaling(0., 1., 1.);
+
+        assert!(c.render(&w).is_err());
+    }
+
+    #[test]
+    fn ray_through_pixel_offset() {
+        let c = Camera::new(201, 101, PI / 2.);
+
+        let centre = c.ray_for_pixel(100, 50).unwrap();
+        let r = c.ray_for_pixel_offset(100, 50, 0.5, 0.5).unwrap();
+        assert_eq!((r.origin, r.direction), (centre.origin, centre.direction));
+
+        let r = c.ray_for_pixel_offset(0, 0, 0., 0.).unwrap();
+        let corner = Tuple::new_point(c.half_width, c.half_height, -1.);
+        assert_eq!(r.direction, (corner - Tuple::new_point(0., 0., 0.)).normalize());
+    }
+
+    #[test]
+    fn ray_from_lens_meets_at_focal_distance() {
+        let mut c = Camera::new(201, 101, PI / 2.);
+        c.aperture = 1.;
+        c.focal_distance = 3.;
+
+        let r = c.ray_for_pixel_on_lens(100, 50, 0.5, 0.5, (0.3, -0.2)).unwrap();
+        assert_eq!(r.origin, Tuple::new_point(0.3, -0.2, 0.));
+        let t = 3. / -r.direction.z;
+        assert_eq!(r.position(t), Tuple::new_point(0., 0., -3.));
+
+        let r = c.ray_for_pixel_on_lens(0, 0, 0.5, 0.5, (0.4, 0.1)).unwrap();
+        let pinhole = c.ray_for_pixel(0, 0).unwrap();
+        let t = 3. / -r.direction.z;
+        assert_eq!(r.position(t), pinhole.position(3. / -pinhole.direction.z));
+    }
+
+    #[test]
+    fn lens_points() {
+        let mut c = Camera::new(10, 10, PI / 2.);
+        let mut rng = Rng::new(0);
+        assert_eq!(c.lens_point(&mut rng), (0., 0.));
+
+        c.aperture = 2.;
+        let points: Vec<(f64, f64)> = (0..100).map(|_| c.lens_point(&mut rng)).collect();
+        assert!(points.iter().all(|(x, y)| x * x + y * y <= 1.));
+        assert!(points.iter().any(|(x, _)| *x < 0.) && points.iter().any(|(x, _)| *x > 0.));
+    }
+
+    #[test]
+    fn grid_sample_offsets() {
+        let mut c = Camera::new(10, 10, PI / 2.);
+        let mut rng = Rng::new(0);
+        assert_eq!(c.sample_offsets(&mut rng), vec![(0.5, 0.5)]);
+
+        c.samples = 4;
+        assert_eq!(c.sample_offsets(&mut rng), vec![(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)]);
+
+        // Rounded up to a full grid
+        c.samples = 5;
+        assert_eq!(c.sample_offsets(&mut rng).len(), 9);
+    }
+
+    #[test]
+    fn jittered_sample_offsets() {
+        let mut c = Camera::new(10, 10, PI / 2.);
+        c.samples = 4;
+        c.sampling = Sampling::Jittered;
+        let offsets = c.sample_offsets(&mut Rng::new(3));
+
+        assert_eq!(offsets.len(), 4);
+        // Each sample stays inside its own cell
+        for (i, (x, y)) in offsets.iter().enumerate() {
+            let (column, row) = ((i % 2) as f64, (i / 2) as f64);
+            assert!(*x >= column * 0.5 && *x < (column + 1.) * 0.5);
+            assert!(*y >= row * 0.5 && *y < (row + 1.) * 0.5);
+        }
+        assert_ne!(offsets, c.sample_offsets(&mut Rng::new(4)));
+    }
+
+    #[test]
+    fn random_sample_offsets() {
+        let mut c = Camera::new(10, 10, PI / 2.);
+        c.samples = 5;
+        c.sampling = Sampling::Random;
+        let offsets = c.sample_offsets(&mut Rng::new(3));
+
+        assert_eq!(offsets.len(), 5);
+        for (x, y) in offsets.iter() {
+            assert!((0. ..1.).contains(x) && (0. ..1.).contains(y));
+        }
+    }
+
+    #[test]
+    fn supersampling_smooths_edges() {
+        let w = World {
+            objects: vec![Drawables::Sphere(Sphere::new())],
+            ..Default::default()
+        };
+        let mut c = Camera::new(9, 9, PI / 2.);
+        c.transform = Matrix4::new_view_transform(
+            Tuple::new_point(0., 0., -2.),
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(0., 1., 0.)
+        );
+        let sharp = c.render(&w).unwrap();
+
+        c.samples = 16;
+        let smooth = c.render(&w).unwrap();
+
+        // Pixels well inside or outside of the sphere don't change much,
+        // but the ones on its edge get blended with the background
+        assert_eq!(smooth.get_color(0, 0).unwrap(), Color::new(0., 0., 0.));
+        let centre = smooth.get_color(4, 4).unwrap() - sharp.get_color(4, 4).unwrap();
+        assert!(centre.r().abs() < 0.01);
+        let blended = (0..9)
+            .flat_map(|y| (0..9).map(move |x| (x, y)))
+            .filter(|(x, y)| sharp.get_color(*x, *y) != smooth.get_color(*x, *y))
+            .count();
+        assert!(blended > 0);
+    }
+
+    #[test]
+    fn jittered_render_is_repeatable() {
+        let w: World = Default::default();
+        let mut c = Camera::new(5, 5, PI / 2.);
+        c.transform = Matrix4::new_view_transform(
+            Tuple::new_point(0., 0., -5.),
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(0., 1., 0.)
+        );
+        c.samples = 4;
+        c.sampling = Sampling::Jittered;
+
+        assert_eq!(c.render(&w).unwrap().data, c.render(&w).unwrap().data);
+    }
+
+    #[test]
+    fn adaptive_only_refines_edges() {
+        // A flat colored sphere, so that only its edge stands out
+        let mut s = Sphere::new();
+        s.shape.material = Material {
+            ambient: 1.,
+            diffuse: 0.,
+            specular: 0.,
+            ..Default::default()
+        };
+        let w = World {
+            objects: vec![Drawables::Sphere(s)],
+            ..Default::default()
+        };
+        let mut c = Camera::new(9, 9, PI / 2.);
+        c.transform = Matrix4::new_view_transform(
+            Tuple::new_point(0., 0., -2.),
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(0., 1., 0.)
+        );
+        let sharp = c.render(&w).unwrap();
+
+        let adaptive = Adaptive { threshold: 0.1, max_depth: 2 };
+        let (smooth, map) = c.render_adaptive(&w, adaptive).unwrap();
+
+        // The corners are background all around and the centre is sphere all
+        // around, so only pixels along the edge of the sphere get refined
+        assert_eq!(map.get_color(0, 0).unwrap(), Color::new(0., 0., 0.));
+        assert_eq!(map.get_color(4, 4).unwrap(), Color::new(0., 0., 0.));
+        assert_eq!(smooth.get_color(0, 0), sharp.get_color(0, 0));
+        assert_eq!(smooth.get_color(4, 4), sharp.get_color(4, 4));
+
+        let refined: Vec<(usize, usize)> = (0..9)
+            .flat_map(|y| (0..9).map(move |x| (x, y)))
+            .filter(|(x, y)| map.get_color(*x, *y).unwrap() != Color::new(0., 0., 0.))
+            .collect();
+        assert!(!refined.is_empty());
+        assert!(refined.iter().any(|(x, y)| sharp.get_color(*x, *y) != smooth.get_color(*x, *y)));
+        for (x, y) in refined {
+            assert!(map.get_color(x, y).unwrap().r() <= 1.);
+        }
+
+        c.adaptive = Some(adaptive);
+        assert_eq!(c.render(&w).unwrap().data, smooth.data);
+    }
+
+    #[test]
+    fn adaptive_render_of_flat_image() {
+        let w = World {
+            objects: vec![],
+            ..Default::default()
+        };
+        let c = Camera::new(5, 5, PI / 2.);
+
+        let (canvas, map) = c.render_adaptive(&w, Adaptive { threshold: 0., max_depth: 3 }).unwrap();
+        assert!(canvas.data.iter().flatten().all(|c| *c == Color::new(0., 0., 0.)));
+        assert!(map.data.iter().flatten().all(|c| *c == Color::new(0., 0., 0.)));
+    }
+
+    #[test]
+    fn depth_of_field_blurs_what_is_out_of_focus() {
+        let w = World {
+            objects: vec![Drawables::Sphere(Sphere::new())],
+            ..Default::default()
+        };
+        let mut c = Camera::new(9, 9, PI / 2.);
+        c.transform = Matrix4::new_view_transform(
+            Tuple::new_point(0., 0., -2.),
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(0., 1., 0.)
+        );
+        let sharp = c.render(&w).unwrap();
+
+        let difference = |canvas: &Canvas| {
+            canvas.data.iter().flatten().zip(sharp.data.iter().flatten())
+                .map(|(a, b)| (a.r() - b.r()).abs() + (a.g() - b.g()).abs() + (a.b() - b.b()).abs())
+                .sum::<f64>()
+        };
+
+        // Focused on the front of the sphere it stays close to the sharp
+        // image, focused far behind it everything gets smeared
+        c.samples = 16;
+        c.aperture = 0.5;
+        c.focal_distance = 1.;
+        let focused = difference(&c.render(&w).unwrap());
+        c.focal_distance = 10.;
+        let blurred = difference(&c.render(&w).unwrap());
+
+        assert!(focused < blurred);
+    }
 }
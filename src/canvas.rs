@@ -1,6 +1,18 @@
+use std::fs;
+use std::path::Path;
+
 use crate::color::Color;
 
+// How many bits each color channel gets when saved as png
 #[allow(dead_code)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BitDepth {
+    Eight,
+    Sixteen,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Canvas {
     pub data: Vec<Vec<Color>>
 }
@@ -38,6 +50,7 @@ impl Canvas {
         self.data = vec![vec![color; self.get_width()]; self.get_height()]
     }
 
+    #[allow(clippy::result_unit_err)]
     pub fn get_color(&self, column: usize, row: usize) -> Result<Color, ()>{
         let columns = self.data.len();
         let rows = self.data[0].len();
@@ -99,6 +112,247 @@ impl Canvas {
         string
     }
 
+    // P6, which is a lot smaller than the plain text P3 from to_ppm
+    pub fn to_ppm_binary(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.get_width(), self.get_height()).into_bytes();
+        for row in &self.data {
+            for color in row {
+                ppm.push(Self::map_color(color.r()) as u8);
+                ppm.push(Self::map_color(color.g()) as u8);
+                ppm.push(Self::map_color(color.b()) as u8);
+            }
+        }
+        ppm
+    }
+
+    // Reads both P3 and P6 files, with any maxval up to 65535
+    pub fn from_ppm(bytes: &[u8]) -> Result<Self, &'static str> {
+        let mut pos = 0;
+        let binary = match Self::ppm_token(bytes, &mut pos) {
+            Some(b"P3") => false,
+            Some(b"P6") => true,
+            _ => return Err("Not a ppm file"),
+        };
+
+        let width = Self::ppm_number(bytes, &mut pos)?;
+        let height = Self::ppm_number(bytes, &mut pos)?;
+        let max = Self::ppm_number(bytes, &mut pos)?;
+        if width == 0 || height == 0 {
+            return Err("The ppm file has no pixels");
+        }
+        if max == 0 || max > 65535 {
+            return Err("The ppm maxval has to be between 1 and 65535");
+        }
+
+        // The header can claim any size, so nothing is allocated for the
+        // pixels until we know the file can hold them
+        let count = match width.checked_mul(height).and_then(|n| n.checked_mul(3)) {
+            Some(count) => count,
+            None => return Err("The ppm file is too large"),
+        };
+
+        let mut samples;
+        if binary {
+            // Exactly one whitespace character separates the header from the pixels
+            pos += 1;
+            let sample_size = if max < 256 { 1 } else { 2 };
+            let end = match count.checked_mul(sample_size).and_then(|n| n.checked_add(pos)) {
+                Some(end) => end,
+                None => return Err("The ppm file is too large"),
+            };
+            if bytes.len() < end {
+                return Err("The ppm file is missing pixels");
+            }
+
+            samples = Vec::with_capacity(count);
+            for sample in bytes[pos..end].chunks(sample_size) {
+                samples.push(sample.iter().fold(0, |value, byte| (value << 8) | *byte as usize));
+            }
+        } else {
+            // Every sample takes at least a digit and a space
+            samples = Vec::with_capacity(count.min(bytes.len() / 2));
+            for _ in 0..count {
+                samples.push(Self::ppm_number(bytes, &mut pos)?);
+            }
+        }
+
+        if samples.iter().any(|s| *s > max) {
+            return Err("A ppm pixel is larger than the maxval");
+        }
+
+        let mut canvas = Canvas::new(width, height);
+        for (i, rgb) in samples.chunks(3).enumerate() {
+            canvas.data[i / width][i % width] = Color::new(
+                rgb[0] as f64 / max as f64,
+                rgb[1] as f64 / max as f64,
+                rgb[2] as f64 / max as f64,
+            );
+        }
+
+        Ok(canvas)
+    }
+
+    // Skips whitespace and comments, and returns the next word
+    fn ppm_token<'a>(bytes: &'a [u8], pos: &mut usize) -> Option<&'a [u8]> {
+        while *pos < bytes.len() {
+            if bytes[*pos] == b'#' {
+                while *pos < bytes.len() && bytes[*pos] != b'\n' {
+                    *pos += 1;
+                }
+            } else if bytes[*pos].is_ascii_whitespace() {
+                *pos += 1;
+            } else {
+                break;
+            }
+        }
+
+        let start = *pos;
+        while *pos < bytes.len() && !bytes[*pos].is_ascii_whitespace() && bytes[*pos] != b'#' {
+            *pos += 1;
+        }
+
+        if start == *pos {
+            return None;
+        }
+        Some(&bytes[start..*pos])
+    }
+
+    fn ppm_number(bytes: &[u8], pos: &mut usize) -> Result<usize, &'static str> {
+        let token = match Self::ppm_token(bytes, pos) {
+            Some(token) => token,
+            None => return Err("The ppm file ended too early"),
+        };
+
+        match std::str::from_utf8(token).ok().and_then(|t| t.parse::<usize>().ok()) {
+            Some(number) => Ok(number),
+            None => Err("The ppm file has a malformed number"),
+        }
+    }
+
+    pub fn from_png(bytes: &[u8]) -> Result<Self, &'static str> {
+        let mut decoder = png::Decoder::new(bytes);
+        // Palettes and tiny bit depths are turned into plain 8 bit channels
+        decoder.set_transformations(png::Transformations::EXPAND);
+        let mut reader = match decoder.read_info() {
+            Ok(reader) => reader,
+            Err(_) => return Err("Not a png file"),
+        };
+
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = match reader.next_frame(&mut buffer) {
+            Ok(info) => info,
+            Err(_) => return Err("Could not read the png image data"),
+        };
+
+        let (sample_size, max) = match info.bit_depth {
+            png::BitDepth::Sixteen => (2, 65535.),
+            _ => (1, 255.),
+        };
+        let channels = match info.color_type {
+            png::ColorType::Grayscale => 1,
+            png::ColorType::GrayscaleAlpha => 2,
+            png::ColorType::Rgb => 3,
+            png::ColorType::Rgba => 4,
+            png::ColorType::Indexed => return Err("Could not expand the png palette"),
+        };
+
+        let width = info.width as usize;
+        let mut canvas = Canvas::new(width, info.height as usize);
+        for (y, line) in buffer[..info.buffer_size()].chunks(info.line_size).enumerate() {
+            for x in 0..width {
+                let pixel = &line[x * channels * sample_size..(x + 1) * channels * sample_size];
+                let sample = |i: usize| {
+                    let bytes = &pixel[i * sample_size..(i + 1) * sample_size];
+                    bytes.iter().fold(0, |value, byte| (value << 8) | *byte as usize) as f64 / max
+                };
+
+                // Alpha is ignored
+                canvas.data[y][x] = if channels < 3 {
+                    Color::new(sample(0), sample(0), sample(0))
+                } else {
+                    Color::new(sample(0), sample(1), sample(2))
+                };
+            }
+        }
+
+        Ok(canvas)
+    }
+
+    // The format is picked from the extension of the path
+    pub fn load(path: &str) -> Result<Self, String> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) => return Err(format!("could not read {}: {}", path, e)),
+        };
+
+        let canvas = match Self::extension(path).as_deref() {
+            Some("png") => Self::from_png(&bytes),
+            Some("ppm") => Self::from_ppm(&bytes),
+            _ => return Err(format!("don't know how to load {}, use a .png or .ppm file", path)),
+        };
+
+        canvas.map_err(|e| format!("{}: {}", path, e))
+    }
+
+    fn extension(path: &str) -> Option<String> {
+        Path::new(path)
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+    }
+
+    pub fn to_png(&self, depth: BitDepth) -> Result<Vec<u8>, &'static str> {
+        let mut pixels = vec![];
+        for row in &self.data {
+            for color in row {
+                for channel in [color.r(), color.g(), color.b()].iter() {
+                    match depth {
+                        BitDepth::Eight => pixels.push(Self::map_color(*channel) as u8),
+                        BitDepth::Sixteen => {
+                            let value = (channel * 65535_f64).clamp(0.0, 65535.0).round() as u16;
+                            pixels.extend_from_slice(&value.to_be_bytes());
+                        },
+                    }
+                }
+            }
+        }
+
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, self.get_width() as u32, self.get_height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(match depth {
+            BitDepth::Eight => png::BitDepth::Eight,
+            BitDepth::Sixteen => png::BitDepth::Sixteen,
+        });
+
+        let mut writer = match encoder.write_header() {
+            Ok(writer) => writer,
+            Err(_) => return Err("Could not write the png header"),
+        };
+        if writer.write_image_data(&pixels).is_err() {
+            return Err("Could not write the png image data");
+        }
+        if writer.finish().is_err() {
+            return Err("Could not finish writing the png");
+        }
+
+        Ok(png)
+    }
+
+    // The format is picked from the extension of the path
+    pub fn save(&self, path: &str, depth: BitDepth) -> Result<(), String> {
+        let bytes = match (Self::extension(path).as_deref(), depth) {
+            (Some("png"), _) => self.to_png(depth)?,
+            (Some("ppm"), BitDepth::Eight) => self.to_ppm_binary(),
+            (Some("ppm"), BitDepth::Sixteen) => return Err(String::from("16 bit output is only supported for png files")),
+            _ => return Err(format!("don't know how to save {}, use a .png or .ppm extension", path)),
+        };
+
+        match fs::write(path, bytes) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("could not write {}: {}", path, e)),
+        }
+    }
+
     fn map_color(color: f64) -> i64 {
         (color * 255_f64).max(0.0).min(255.0).round() as i64
     }
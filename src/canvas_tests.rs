@@ -70,4 +70,162 @@ mod canvas_tests {
         assert_eq!(lines.next(), Some("255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204 "));
         assert_eq!(lines.next(), Some("153 255 204 153 255 204 153 255 204 153 255 204 153 "));
     }
+
+    fn decode_png(bytes: &[u8]) -> (png::OutputInfo, Vec<u8>) {
+        let decoder = png::Decoder::new(bytes);
+        let mut reader = decoder.read_info().unwrap();
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).unwrap();
+        buffer.truncate(info.buffer_size());
+        (info, buffer)
+    }
+
+    #[test]
+    fn png_from_canvas() {
+        let mut canvas = Canvas::new(3, 2);
+        canvas.fill(Color::new(0., 0., 0.));
+        canvas.write_pixel(0, 0, Color::new(1.5, 0., 0.)).unwrap();
+        canvas.write_pixel(2, 1, Color::new(-0.5, 0.5, 1.)).unwrap();
+
+        let (info, pixels) = decode_png(&canvas.to_png(BitDepth::Eight).unwrap());
+
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+        assert_eq!(info.bit_depth, png::BitDepth::Eight);
+        assert_eq!(&pixels[0..3], &[255, 0, 0]);
+        assert_eq!(&pixels[15..18], &[0, 128, 255]);
+    }
+
+    #[test]
+    fn sixteen_bit_png_from_canvas() {
+        let mut canvas = Canvas::new(1, 1);
+        canvas.fill(Color::new(1., 0.5, 0.));
+
+        let (info, pixels) = decode_png(&canvas.to_png(BitDepth::Sixteen).unwrap());
+
+        assert_eq!(info.bit_depth, png::BitDepth::Sixteen);
+        assert_eq!(pixels, vec![255, 255, 128, 0, 0, 0]);
+    }
+
+    #[test]
+    fn save_picks_format_from_extension() {
+        let mut canvas = Canvas::new(2, 2);
+        canvas.fill(Color::new(1., 1., 1.));
+        let dir = std::env::temp_dir();
+
+        let png_path = dir.join("canvas_tests_save.png");
+        canvas.save(png_path.to_str().unwrap(), BitDepth::Eight).unwrap();
+        let bytes = std::fs::read(&png_path).unwrap();
+        assert_eq!(&bytes[0..4], b"\x89PNG");
+
+        let ppm_path = dir.join("canvas_tests_save.PPM");
+        canvas.save(ppm_path.to_str().unwrap(), BitDepth::Eight).unwrap();
+        let bytes = std::fs::read(&ppm_path).unwrap();
+        assert_eq!(&bytes[0..3], b"P6\n");
+
+        assert!(canvas.save(ppm_path.to_str().unwrap(), BitDepth::Sixteen).is_err());
+        assert!(canvas.save(dir.join("canvas_tests_save.jpg").to_str().unwrap(), BitDepth::Eight).is_err());
+    }
+
+    #[test]
+    fn binary_ppm_from_canvas() {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.write_pixel(0, 0, Color::new(1., 0.5, 0.)).unwrap();
+        canvas.write_pixel(1, 0, Color::new(0., 0., 2.)).unwrap();
+
+        let ppm = canvas.to_ppm_binary();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\x80\x00\x00\x00\xff".to_vec());
+    }
+
+    #[test]
+    fn read_plain_ppm() {
+        let ppm = "P3
+# a comment
+2 2
+# another one
+100
+0 0 0   100 50 0 # and one after the pixels
+10 20 30
+ 40 50 60
+";
+        let canvas = Canvas::from_ppm(ppm.as_bytes()).unwrap();
+
+        assert_eq!(canvas.get_width(), 2);
+        assert_eq!(canvas.get_height(), 2);
+        assert_eq!(canvas.data[0][1], Color::new(1., 0.5, 0.));
+        assert_eq!(canvas.data[1][0], Color::new(0.1, 0.2, 0.3));
+        assert_eq!(canvas.data[1][1], Color::new(0.4, 0.5, 0.6));
+    }
+
+    #[test]
+    fn read_binary_ppm() {
+        let mut canvas = Canvas::new(3, 2);
+        canvas.fill(Color::new(0.2, 0.4, 0.6));
+        canvas.write_pixel(2, 1, Color::new(1., 0., 1.)).unwrap();
+
+        let read = Canvas::from_ppm(&canvas.to_ppm_binary()).unwrap();
+
+        assert_eq!(read.data, canvas.data);
+    }
+
+    #[test]
+    fn read_sixteen_bit_binary_ppm() {
+        let mut ppm = b"P6 1 1 # comment\n65535\n".to_vec();
+        ppm.extend_from_slice(&[255, 255, 128, 0, 0, 0]);
+
+        let canvas = Canvas::from_ppm(&ppm).unwrap();
+        assert_eq!(canvas.data[0][0], Color::new(1., 0.50001, 0.));
+    }
+
+    #[test]
+    fn malformed_ppm() {
+        let cases: [(&[u8], &str); 11] = [
+            (b"P5\n1 1\n255\n", "Not a ppm file"),
+            (b"", "Not a ppm file"),
+            (b"P3\n1\n", "The ppm file ended too early"),
+            (b"P3\n1 x\n255\n", "The ppm file has a malformed number"),
+            (b"P3\n1 1\n0\n0 0 0", "The ppm maxval has to be between 1 and 65535"),
+            (b"P3\n1 1\n255\n0 300 0", "A ppm pixel is larger than the maxval"),
+            (b"P6\n2 1\n255\n\x00\x00\x00", "The ppm file is missing pixels"),
+            // Huge headers shouldn't try to allocate all of those pixels
+            (b"P3\n100000 100000\n255\n0 0 0", "The ppm file ended too early"),
+            (b"P6\n100000 100000\n255\n\x00\x00\x00", "The ppm file is missing pixels"),
+            (b"P3\n4294967296 4294967296\n255\n0 0 0", "The ppm file is too large"),
+            (b"P6\n4294967296 4294967296\n255\n\x00\x00\x00", "The ppm file is too large"),
+        ];
+
+        for (ppm, message) in cases.iter() {
+            assert_eq!(Canvas::from_ppm(ppm).err(), Some(*message));
+        }
+    }
+
+    #[test]
+    fn read_png() {
+        let mut canvas = Canvas::new(3, 2);
+        canvas.fill(Color::new(0.2, 0.4, 0.6));
+        canvas.write_pixel(1, 1, Color::new(1., 0., 1.)).unwrap();
+
+        let read = Canvas::from_png(&canvas.to_png(BitDepth::Eight).unwrap()).unwrap();
+        assert_eq!(read.data, canvas.data);
+
+        let read = Canvas::from_png(&canvas.to_png(BitDepth::Sixteen).unwrap()).unwrap();
+        assert_eq!(read.data, canvas.data);
+
+        assert_eq!(Canvas::from_png(b"P3\n1 1\n255\n0 0 0").err(), Some("Not a png file"));
+    }
+
+    #[test]
+    fn load_picks_format_from_extension() {
+        let mut canvas = Canvas::new(2, 2);
+        canvas.fill(Color::new(1., 0., 0.));
+        let dir = std::env::temp_dir();
+
+        for name in ["canvas_tests_load.png", "canvas_tests_load.ppm"].iter() {
+            let path = dir.join(name);
+            canvas.save(path.to_str().unwrap(), BitDepth::Eight).unwrap();
+            assert_eq!(Canvas::load(path.to_str().unwrap()).unwrap().data, canvas.data);
+        }
+
+        assert!(Canvas::load(dir.join("canvas_tests_load.gif").to_str().unwrap()).is_err());
+    }
 }
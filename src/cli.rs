@@ -0,0 +1,204 @@
+use super::scene::Scene;
+use super::camera::{Adaptive, Camera, Sampling, ADAPTIVE_DEPTH};
+use super::canvas::BitDepth;
+use super::utils::RECURSION_DEPTH;
+
+pub const USAGE: &str = "Usage: raytracer <scene.yml> [options]
+
+Options:
+    -o, --output <path>     Where to write the image, as .png or .ppm (default: out.png)
+    -W, --width <pixels>    Override the width of the camera
+    -H, --height <pixels>   Override the height of the camera
+    -d, --depth <n>         How many reflections and refractions to follow (default: 5)
+    -s, --samples <n>       Rays per pixel, for anti-aliasing
+        --sampling <type>   Where those rays go: grid, jittered or random
+        --adaptive <diff>   Only refine pixels whose neighbours differ by more than this
+        --adaptive-depth <n>
+                            How many times a refined pixel can be split (default: 2)
+        --refinement-map <path>
+                            Also write an image of the pixels --adaptive refined
+        --aperture <width>  Width of the lens, blurs what is out of focus (default: 0)
+        --focal-distance <distance>
+                            How far away things are in focus
+    -j, --threads <n>       How many threads to render with (default: one per core)
+        --16bit             Use 16 bits per channel for png output
+    -h, --help              Show this message";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub scene: String,
+    pub output: String,
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+    pub depth: usize,
+    pub threads: Option<usize>,
+    pub bit_depth: BitDepth,
+    pub samples: Option<usize>,
+    pub sampling: Option<Sampling>,
+    pub adaptive: Option<f64>,
+    pub adaptive_depth: Option<usize>,
+    pub refinement_map: Option<String>,
+    pub aperture: Option<f64>,
+    pub focal_distance: Option<f64>,
+}
+
+#[allow(dead_code)]
+impl Options {
+    // Expects the arguments without the name of the program.
+    // Returns Ok(None) if the usage was asked for.
+    pub fn parse(args: &[String]) -> Result<Option<Self>, String> {
+        let mut scene = None;
+        let mut options = Self {
+            scene: String::new(),
+            output: String::from("out.png"),
+            width: None,
+            height: None,
+            depth: RECURSION_DEPTH,
+            threads: None,
+            bit_depth: BitDepth::Eight,
+            samples: None,
+            sampling: None,
+            adaptive: None,
+            adaptive_depth: None,
+            refinement_map: None,
+            aperture: None,
+            focal_distance: None,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-o" | "--output" => options.output = Self::value(arg, args.next())?.to_string(),
+                "-W" | "--width" => options.width = Some(Self::number(arg, args.next())?),
+                "-H" | "--height" => options.height = Some(Self::number(arg, args.next())?),
+                "-d" | "--depth" => options.depth = Self::number(arg, args.next())?,
+                "-j" | "--threads" => options.threads = Some(Self::number(arg, args.next())?),
+                "--16bit" => options.bit_depth = BitDepth::Sixteen,
+                "-s" | "--samples" => options.samples = Some(Self::number(arg, args.next())?),
+                "--sampling" => options.sampling = Some(Self::sampling(arg, args.next())?),
+                "--adaptive" => options.adaptive = Some(Self::non_negative(arg, args.next())?),
+                "--adaptive-depth" => options.adaptive_depth = Some(Self::number(arg, args.next())?),
+                "--refinement-map" => options.refinement_map = Some(Self::value(arg, args.next())?.to_string()),
+                "--aperture" => options.aperture = Some(Self::non_negative(arg, args.next())?),
+                "--focal-distance" => options.focal_distance = Some(Self::positive(arg, args.next())?),
+                _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+                _ if scene.is_some() => return Err(format!("unexpected argument {}", arg)),
+                _ => scene = Some(arg.to_string()),
+            }
+        }
+
+        match scene {
+            Some(scene) => options.scene = scene,
+            None => return Err(String::from("no scene file given")),
+        }
+
+        Ok(Some(options))
+    }
+
+    fn value<'a>(option: &str, value: Option<&'a String>) -> Result<&'a str, String> {
+        match value {
+            Some(value) => Ok(value),
+            None => Err(format!("{} needs a value", option)),
+        }
+    }
+
+    fn number(option: &str, value: Option<&String>) -> Result<usize, String> {
+        let value = Self::value(option, value)?;
+        match value.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("{} expects a positive whole number, got {}", option, value)),
+        }
+    }
+
+    fn non_negative(option: &str, value: Option<&String>) -> Result<f64, String> {
+        let value = Self::value(option, value)?;
+        match value.parse::<f64>() {
+            Ok(n) if n >= 0. => Ok(n),
+            _ => Err(format!("{} expects a number of at least 0, got {}", option, value)),
+        }
+    }
+
+    fn positive(option: &str, value: Option<&String>) -> Result<f64, String> {
+        let value = Self::value(option, value)?;
+        match value.parse::<f64>() {
+            Ok(n) if n > 0. => Ok(n),
+            _ => Err(format!("{} expects a number above 0, got {}", option, value)),
+        }
+    }
+
+    fn sampling(option: &str, value: Option<&String>) -> Result<Sampling, String> {
+        match Self::value(option, value)? {
+            "grid" => Ok(Sampling::Grid),
+            "jittered" => Ok(Sampling::Jittered),
+            "random" => Ok(Sampling::Random),
+            value => Err(format!("{} expects grid, jittered or random, got {}", option, value)),
+        }
+    }
+
+    // Applies the overrides to the camera from the scene. If only one side
+    // of the size is given, the other one keeps the aspect ratio of the scene.
+    pub fn apply_to_camera(&self, camera: Camera) -> Camera {
+        let aspect = camera.h_size as f64 / camera.v_size as f64;
+        let (width, height) = match (self.width, self.height) {
+            (Some(w), Some(h)) => (w, h),
+            (Some(w), None) => (w, ((w as f64 / aspect).round() as usize).max(1)),
+            (None, Some(h)) => (((h as f64 * aspect).round() as usize).max(1), h),
+            (None, None) => (camera.h_size, camera.v_size),
+        };
+
+        let mut camera = camera;
+        camera.resize(width, height);
+        camera.recursion_depth = self.depth;
+        if let Some(samples) = self.samples {
+            camera.samples = samples;
+        }
+        if let Some(sampling) = self.sampling {
+            camera.sampling = sampling;
+        }
+        if let Some(threshold) = self.adaptive {
+            camera.adaptive = Some(Adaptive {
+                threshold,
+                max_depth: camera.adaptive.map_or(ADAPTIVE_DEPTH, |a| a.max_depth),
+            });
+        }
+        if let (Some(max_depth), Some(adaptive)) = (self.adaptive_depth, camera.adaptive.as_mut()) {
+            adaptive.max_depth = max_depth;
+        }
+        if let Some(aperture) = self.aperture {
+            camera.aperture = aperture;
+        }
+        if let Some(focal_distance) = self.focal_distance {
+            camera.focal_distance = focal_distance;
+        }
+        camera
+    }
+}
+
+pub fn run(options: &Options) -> Result<(), String> {
+    if let Some(threads) = options.threads {
+        if let Err(e) = rayon::ThreadPoolBuilder::new().num_threads(threads).build_global() {
+            return Err(format!("could not start {} threads: {}", threads, e));
+        }
+    }
+
+    let scene = match Scene::from_file(&options.scene) {
+        Ok(scene) => scene,
+        Err(e) => return Err(format!("{}: {}", options.scene, e)),
+    };
+
+    let camera = options.apply_to_camera(scene.camera);
+    if options.adaptive_depth.is_some() && camera.adaptive.is_none() {
+        return Err(String::from("--adaptive-depth only works with adaptive anti-aliasing, see --adaptive"));
+    }
+
+    match (&options.refinement_map, camera.adaptive) {
+        (Some(path), Some(adaptive)) => {
+            let (canvas, refinement_map) = camera.render_adaptive(&scene.world, adaptive)?;
+            canvas.save(&options.output, options.bit_depth)?;
+            refinement_map.save(path, options.bit_depth)
+        },
+        (Some(_), None) => Err(String::from("--refinement-map only works with adaptive anti-aliasing, see --adaptive")),
+        (None, _) => camera.render(&scene.world)?.save(&options.output, options.bit_depth),
+    }
+}
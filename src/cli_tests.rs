@@ -0,0 +1,150 @@
+#[cfg(test)]
+
+mod cli_tests {
+    use std::f64::consts::PI;
+    use crate::cli::*;
+    use crate::camera::{Adaptive, Camera, Sampling, ADAPTIVE_DEPTH};
+    use crate::canvas::BitDepth;
+    use crate::matrix::Matrix4;
+    use crate::utils::RECURSION_DEPTH;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn default_options() {
+        let options = Options::parse(&args("scene.yml")).unwrap().unwrap();
+
+        assert_eq!(options, Options {
+            scene: String::from("scene.yml"),
+            output: String::from("out.png"),
+            width: None,
+            height: None,
+            depth: RECURSION_DEPTH,
+            threads: None,
+            bit_depth: BitDepth::Eight,
+            samples: None,
+            sampling: None,
+            adaptive: None,
+            adaptive_depth: None,
+            refinement_map: None,
+            aperture: None,
+            focal_distance: None,
+        });
+    }
+
+    #[test]
+    fn every_option() {
+        let options = Options::parse(&args("-o a.png --width 10 -H 20 -d 2 --threads 3 --16bit -s 4 --sampling jittered --adaptive 0.1 --adaptive-depth 3 --refinement-map map.png --aperture 0.5 --focal-distance 4 scene.yml")).unwrap().unwrap();
+
+        assert_eq!(options, Options {
+            scene: String::from("scene.yml"),
+            output: String::from("a.png"),
+            width: Some(10),
+            height: Some(20),
+            depth: 2,
+            threads: Some(3),
+            bit_depth: BitDepth::Sixteen,
+            samples: Some(4),
+            sampling: Some(Sampling::Jittered),
+            adaptive: Some(0.1),
+            adaptive_depth: Some(3),
+            refinement_map: Some(String::from("map.png")),
+            aperture: Some(0.5),
+            focal_distance: Some(4.),
+        });
+    }
+
+    #[test]
+    fn asking_for_help() {
+        assert_eq!(Options::parse(&args("scene.yml --help")), Ok(None));
+    }
+
+    #[test]
+    fn bad_arguments() {
+        let cases = [
+            ("", "no scene file given"),
+            ("scene.yml --frobnicate", "unknown option --frobnicate"),
+            ("scene.yml other.yml", "unexpected argument other.yml"),
+            ("scene.yml -o", "-o needs a value"),
+            ("scene.yml --width wide", "--width expects a positive whole number, got wide"),
+            ("scene.yml -j 0", "-j expects a positive whole number, got 0"),
+            ("scene.yml --sampling sometimes", "--sampling expects grid, jittered or random, got sometimes"),
+            ("scene.yml --adaptive -1", "--adaptive expects a number of at least 0, got -1"),
+            ("scene.yml --focal-distance 0", "--focal-distance expects a number above 0, got 0"),
+        ];
+
+        for (line, message) in cases.iter() {
+            assert_eq!(Options::parse(&args(line)), Err(message.to_string()));
+        }
+    }
+
+    #[test]
+    fn override_camera_size() {
+        let mut camera = Camera::new(200, 100, PI / 2.);
+        camera.transform = Matrix4::new_translation(1., 2., 3.);
+
+        let options = Options::parse(&args("scene.yml -W 50 -d 1 -s 9 --sampling random --aperture 0.2 --focal-distance 3")).unwrap().unwrap();
+        let resized = options.apply_to_camera(camera);
+        assert_eq!((resized.h_size, resized.v_size), (50, 25));
+        assert_eq!(resized.transform, camera.transform);
+        assert_eq!(resized.field_of_view, camera.field_of_view);
+        assert_eq!(resized.pixel_size, Camera::new(50, 25, PI / 2.).pixel_size);
+        assert_eq!(resized.recursion_depth, 1);
+        assert_eq!((resized.samples, resized.sampling), (9, Sampling::Random));
+        assert_eq!((resized.aperture, resized.focal_distance), (0.2, 3.));
+
+        let options = Options::parse(&args("scene.yml -H 50")).unwrap().unwrap();
+        let resized = options.apply_to_camera(camera);
+        assert_eq!((resized.h_size, resized.v_size), (100, 50));
+    }
+
+    #[test]
+    fn override_camera_adaptive() {
+        let camera = Camera::new(10, 10, PI / 2.);
+
+        let options = Options::parse(&args("scene.yml --adaptive-depth 4")).unwrap().unwrap();
+        assert_eq!(options.apply_to_camera(camera).adaptive, None);
+
+        let options = Options::parse(&args("scene.yml --adaptive 0.2")).unwrap().unwrap();
+        assert_eq!(options.apply_to_camera(camera).adaptive, Some(Adaptive {
+            threshold: 0.2,
+            max_depth: ADAPTIVE_DEPTH,
+        }));
+
+        let mut camera = camera;
+        camera.adaptive = Some(Adaptive { threshold: 0.1, max_depth: 1 });
+        let options = Options::parse(&args("scene.yml --adaptive-depth 4")).unwrap().unwrap();
+        assert_eq!(options.apply_to_camera(camera).adaptive, Some(Adaptive {
+            threshold: 0.1,
+            max_depth: 4,
+        }));
+    }
+
+    #[test]
+    fn adaptive_options_without_adaptive() {
+        let path = std::env::temp_dir().join("cli_tests_scene.yml");
+        std::fs::write(&path, "- add: camera
+  width: 1
+  height: 1
+  field-of-view: 0.785
+  from: [0, 0, -5]
+  to: [0, 0, 0]
+  up: [0, 1, 0]
+").unwrap();
+        let scene = path.to_str().unwrap();
+
+        let options = Options::parse(&args(&format!("{} --adaptive-depth 3", scene))).unwrap().unwrap();
+        assert_eq!(run(&options), Err(String::from("--adaptive-depth only works with adaptive anti-aliasing, see --adaptive")));
+
+        let options = Options::parse(&args(&format!("{} --refinement-map map.png", scene))).unwrap().unwrap();
+        assert_eq!(run(&options), Err(String::from("--refinement-map only works with adaptive anti-aliasing, see --adaptive")));
+    }
+
+    #[test]
+    fn missing_scene_file() {
+        let options = Options::parse(&args("does_not_exist.yml")).unwrap().unwrap();
+        assert_eq!(run(&options), Err(String::from("does_not_exist.yml: could not read scene file does_not_exist.yml")));
+    }
+}
@@ -0,0 +1,158 @@
+use super::utils::EPSILON;
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::Drawable;
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cone {
+    pub shape: Shape,
+    pub minimum: f64,
+    pub maximum: f64,
+    pub closed: bool,
+}
+
+#[allow(dead_code)]
+impl Cone {
+    pub fn new() -> Self {
+        let shape: Shape = Default::default();
+        Self {
+            shape,
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn new_with_transform(transform: Matrix4) -> Self {
+        let shape = Shape::new_with_transform(transform);
+        Self {
+            shape,
+            ..Self::new()
+        }
+    }
+
+    // The radius of a cone's cap is the absolute y value it was cut at
+    fn check_cap(ray: Ray, t: f64, radius: f64) -> bool {
+        let x = ray.origin.x + t * ray.direction.x;
+        let z = ray.origin.z + t * ray.direction.z;
+
+        (x.powf(2.) + z.powf(2.)) <= radius.powf(2.)
+    }
+
+    fn intersect_caps(&self, ray: Ray, xs: &mut Vec<f64>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        let t = (self.minimum - ray.origin.y) / ray.direction.y;
+        if Self::check_cap(ray, t, self.minimum.abs()) {
+            xs.push(t);
+        }
+
+        let t = (self.maximum - ray.origin.y) / ray.direction.y;
+        if Self::check_cap(ray, t, self.maximum.abs()) {
+            xs.push(t);
+        }
+    }
+}
+
+impl Drawable for Cone {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let mut xs: Vec<f64> = vec![];
+
+        let origin = local_ray.origin;
+        let direction = local_ray.direction;
+
+        let a = direction.x.powf(2.) - direction.y.powf(2.) + direction.z.powf(2.);
+        let b = 2. * origin.x * direction.x -
+                2. * origin.y * direction.y +
+                2. * origin.z * direction.z;
+        let c = origin.x.powf(2.) - origin.y.powf(2.) + origin.z.powf(2.);
+
+        if a.abs() < EPSILON {
+            // The ray is parallel to one of the cone's halves,
+            // so it can only hit the other half once
+            if b.abs() >= EPSILON {
+                let t = -c / (2. * b);
+                let y = origin.y + t * direction.y;
+                if self.minimum < y && y < self.maximum {
+                    xs.push(t);
+                }
+            }
+        } else {
+            let discriminant = b.powf(2.) - 4. * a * c;
+            if discriminant < 0. {
+                return None;
+            }
+
+            let mut t0 = (-b - discriminant.sqrt()) / (2. * a);
+            let mut t1 = (-b + discriminant.sqrt()) / (2. * a);
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            let y0 = origin.y + t0 * direction.y;
+            if self.minimum < y0 && y0 < self.maximum {
+                xs.push(t0);
+            }
+
+            let y1 = origin.y + t1 * direction.y;
+            if self.minimum < y1 && y1 < self.maximum {
+                xs.push(t1);
+            }
+        }
+
+        self.intersect_caps(local_ray, &mut xs);
+
+        if xs.is_empty() {
+            return None;
+        }
+        Some(xs)
+    }
+
+    fn local_normal_at(&self, local_point: Tuple, _: &Intersection) -> Tuple {
+        let distance = local_point.x.powf(2.) + local_point.z.powf(2.);
+
+        if distance < self.maximum.powf(2.) && local_point.y >= self.maximum - EPSILON {
+            return Tuple::new_vector(0., 1., 0.);
+        } else if distance < self.minimum.powf(2.) && local_point.y <= self.minimum + EPSILON {
+            return Tuple::new_vector(0., -1., 0.);
+        }
+
+        let mut y = distance.sqrt();
+        if local_point.y > 0. {
+            y = -y;
+        }
+        Tuple::new_vector(local_point.x, y, local_point.z)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+        BoundingBox::new(
+            Tuple::new_point(-limit, self.minimum, -limit),
+            Tuple::new_point(limit, self.maximum, limit)
+        )
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
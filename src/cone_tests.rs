@@ -0,0 +1,103 @@
+#[cfg(test)]
+
+mod cone_tests {
+    use crate::cone::*;
+    use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
+    use crate::ray::Ray;
+    use crate::matrix::Matrix4;
+    use crate::generics::{Drawable, Drawables};
+    use crate::utils::is_equal;
+
+    #[test]
+    fn ray_intersects_cone() {
+        let c = Cone::new();
+        let cases = [
+            (Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.), 5., 5.),
+            (Tuple::new_point(0., 0., -5.), Tuple::new_vector(1., 1., 1.), 8.66025, 8.66025),
+            (Tuple::new_point(1., 1., -5.), Tuple::new_vector(-0.5, -1., 1.), 4.55006, 49.44994),
+        ];
+
+        for (origin, direction, t0, t1) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            let xs = c.local_intersect(r).unwrap();
+            assert_eq!(xs.len(), 2);
+            assert!(is_equal(xs[0], *t0));
+            assert!(is_equal(xs[1], *t1));
+        }
+    }
+
+    #[test]
+    fn ray_parallel_to_one_half() {
+        let c = Cone::new();
+        let r = Ray::new(Tuple::new_point(0., 0., -1.), Tuple::new_vector(0., 1., 1.).normalize()).unwrap();
+        let xs = c.local_intersect(r).unwrap();
+
+        assert_eq!(xs.len(), 1);
+        assert!(is_equal(xs[0], 0.35355));
+    }
+
+    #[test]
+    fn intersect_caps_of_closed_cone() {
+        let mut c = Cone::new();
+        c.minimum = -0.5;
+        c.maximum = 0.5;
+        c.closed = true;
+
+        let cases = [
+            (Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 1., 0.), 0),
+            (Tuple::new_point(0., 0., -0.25), Tuple::new_vector(0., 1., 1.), 2),
+            (Tuple::new_point(0., 0., -0.25), Tuple::new_vector(0., 1., 0.), 4),
+        ];
+
+        for (origin, direction, count) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            let xs = c.local_intersect(r);
+            assert_eq!(xs.map_or(0, |xs| xs.len()), *count);
+        }
+    }
+
+    #[test]
+    fn normal_on_cone() {
+        let c = Cone::new();
+        let d = Drawables::Cone(c.clone());
+        let i = Intersection::new(0., &d);
+        let cases = [
+            (Tuple::new_point(0., 0., 0.), Tuple::new_vector(0., 0., 0.)),
+            (Tuple::new_point(1., 1., 1.), Tuple::new_vector(1., -2_f64.sqrt(), 1.)),
+            (Tuple::new_point(-1., -1., 0.), Tuple::new_vector(-1., 1., 0.)),
+        ];
+
+        for (point, normal) in cases.iter() {
+            assert_eq!(c.local_normal_at(*point, &i), *normal);
+        }
+    }
+
+    #[test]
+    fn normal_on_cone_end_caps() {
+        let mut c = Cone::new();
+        c.minimum = -1.;
+        c.maximum = 2.;
+        c.closed = true;
+        let d = Drawables::Cone(c.clone());
+        let i = Intersection::new(0., &d);
+
+        assert_eq!(c.local_normal_at(Tuple::new_point(0.5, 2., 0.), &i), Tuple::new_vector(0., 1., 0.));
+        assert_eq!(c.local_normal_at(Tuple::new_point(0., -1., 0.5), &i), Tuple::new_vector(0., -1., 0.));
+    }
+
+    #[test]
+    fn intersect_transformed_cone() {
+        let mut cone = Cone::new_with_transform(Matrix4::new_translation(0., 1., 0.));
+        cone.minimum = -1.;
+        cone.maximum = 0.;
+        cone.closed = true;
+        let c = Drawables::Cone(cone);
+
+        let r = Ray::new(Tuple::new_point(0., 5., 0.), Tuple::new_vector(0., -1., 0.)).unwrap();
+        let xs = r.intersect(&c).unwrap();
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(*xs[0].object, c);
+    }
+}
@@ -0,0 +1,159 @@
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::{Drawable, Drawables};
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+// Constructive solid geometry, combining two drawables into one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Csg {
+    pub shape: Shape,
+    pub operation: CsgOperation,
+    left: Box<Drawables>,
+    right: Box<Drawables>,
+    bounds: BoundingBox,
+}
+
+#[allow(dead_code)]
+impl Csg {
+    pub fn new(operation: CsgOperation, left: Drawables, right: Drawables) -> Self {
+        let mut csg = Self {
+            shape: Default::default(),
+            operation,
+            left: Box::new(left),
+            right: Box::new(right),
+            bounds: BoundingBox::empty(),
+        };
+        csg.bounds.merge(csg.left.parent_space_bounds());
+        csg.bounds.merge(csg.right.parent_space_bounds());
+        csg.update_children();
+        csg
+    }
+
+    pub fn left(&self) -> &Drawables {
+        &self.left
+    }
+
+    pub fn right(&self) -> &Drawables {
+        &self.right
+    }
+
+    // Dividing the children moves things into subgroups, but doesn't change
+    // what they cover, so the bounds stay the same
+    pub fn divide(&mut self, threshold: usize) {
+        self.left.divide(threshold);
+        self.right.divide(threshold);
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.shape.set_transform(transform);
+        self.update_children();
+    }
+
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.shape.set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.shape.get_world_transform();
+        self.left.set_parent_transform(world_transform);
+        self.right.set_parent_transform(world_transform);
+    }
+
+    // left_hit: whether the hit was on the left child
+    // in_left: whether the hit is inside the left child
+    // in_right: whether the hit is inside the right child
+    pub fn intersection_allowed(operation: CsgOperation, left_hit: bool, in_left: bool, in_right: bool) -> bool {
+        match operation {
+            CsgOperation::Union => (left_hit && !in_right) || (!left_hit && !in_left),
+            CsgOperation::Intersection => (left_hit && in_right) || (!left_hit && in_left),
+            CsgOperation::Difference => (left_hit && !in_right) || (!left_hit && in_left),
+        }
+    }
+
+    // Expects the intersections to be sorted
+    pub fn filter_intersections<'a>(&self, xs: Vec<Intersection<'a>>) -> Vec<Intersection<'a>> {
+        let mut in_left = false;
+        let mut in_right = false;
+
+        let mut result = vec![];
+        for i in xs {
+            let left_hit = self.left.includes(i.object);
+
+            if Self::intersection_allowed(self.operation, left_hit, in_left, in_right) {
+                result.push(i);
+            }
+
+            if left_hit {
+                in_left = !in_left;
+            } else {
+                in_right = !in_right;
+            }
+        }
+
+        result
+    }
+
+    // The ray is expected to already be in the space of this csg
+    pub fn intersect_children(&self, local_ray: Ray) -> Option<Vec<Intersection<'_>>> {
+        if !self.bounds.intersects(local_ray) {
+            return None;
+        }
+
+        let mut xs: Vec<Intersection> = vec![];
+
+        if let Some(intersections) = local_ray.intersect(&self.left) {
+            xs.extend(intersections);
+        }
+        if let Some(intersections) = local_ray.intersect(&self.right) {
+            xs.extend(intersections);
+        }
+
+        xs.sort();
+        let xs = self.filter_intersections(xs);
+
+        if xs.is_empty() {
+            return None;
+        }
+        Some(xs)
+    }
+}
+
+impl Drawable for Csg {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let xs = self.intersect_children(local_ray)?;
+        Some(xs.iter().map(|i| i.t).collect())
+    }
+
+    // Intersections are always with one of the children, never the csg itself,
+    // so there is no normal to be found here
+    fn local_normal_at(&self, _: Tuple, _: &Intersection) -> Tuple {
+        unreachable!("Csgs don't have normals, their children do")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
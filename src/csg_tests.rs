@@ -0,0 +1,153 @@
+#[cfg(test)]
+
+mod csg_tests {
+    use crate::csg::*;
+    use crate::sphere::Sphere;
+    use crate::cube::Cube;
+    use crate::tuple::Tuple;
+    use crate::color::Color;
+    use crate::matrix::Matrix4;
+    use crate::ray::Ray;
+    use crate::world::World;
+    use crate::intersection::{Intersection, hit};
+    use crate::generics::{Drawable, Drawables};
+
+    #[test]
+    fn create_csg() {
+        let s1 = Drawables::Sphere(Sphere::new());
+        let s2 = Drawables::Cube(Cube::new());
+        let mut c = Csg::new(CsgOperation::Union, s1.clone(), s2.clone());
+        c.set_transform(Matrix4::new_translation(1., 0., 0.));
+
+        assert_eq!(c.operation, CsgOperation::Union);
+        assert_eq!(c.left().get_shape().get_transform(), s1.get_shape().get_transform());
+        assert_eq!(c.left().get_shape().get_parent_transform(), Matrix4::new_translation(1., 0., 0.));
+        assert_eq!(c.right().get_shape().get_parent_transform(), Matrix4::new_translation(1., 0., 0.));
+    }
+
+    #[test]
+    fn evaluate_rule_for_csg_operation() {
+        use CsgOperation::*;
+        let cases = [
+            (Union, true, true, true, false),
+            (Union, true, true, false, true),
+            (Union, true, false, true, false),
+            (Union, true, false, false, true),
+            (Union, false, true, true, false),
+            (Union, false, true, false, false),
+            (Union, false, false, true, true),
+            (Union, false, false, false, true),
+            (Intersection, true, true, true, true),
+            (Intersection, true, true, false, false),
+            (Intersection, true, false, true, true),
+            (Intersection, true, false, false, false),
+            (Intersection, false, true, true, true),
+            (Intersection, false, true, false, true),
+            (Intersection, false, false, true, false),
+            (Intersection, false, false, false, false),
+            (Difference, true, true, true, false),
+            (Difference, true, true, false, true),
+            (Difference, true, false, true, false),
+            (Difference, true, false, false, true),
+            (Difference, false, true, true, true),
+            (Difference, false, true, false, true),
+            (Difference, false, false, true, false),
+            (Difference, false, false, false, false),
+        ];
+
+        for (op, left_hit, in_left, in_right, result) in cases.iter() {
+            assert_eq!(Csg::intersection_allowed(*op, *left_hit, *in_left, *in_right), *result);
+        }
+    }
+
+    #[test]
+    fn filter_list_of_intersections() {
+        let cases = [
+            (CsgOperation::Union, 0, 3),
+            (CsgOperation::Intersection, 1, 2),
+            (CsgOperation::Difference, 0, 1),
+        ];
+
+        for (op, x0, x1) in cases.iter() {
+            let c = Csg::new(*op, Drawables::Sphere(Sphere::new()), Drawables::Cube(Cube::new()));
+            let xs = vec![
+                Intersection::new(1., c.left()),
+                Intersection::new(2., c.right()),
+                Intersection::new(3., c.left()),
+                Intersection::new(4., c.right()),
+            ];
+
+            let result = c.filter_intersections(xs.clone());
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[0], xs[*x0]);
+            assert_eq!(result[1], xs[*x1]);
+        }
+    }
+
+    #[test]
+    fn ray_misses_csg() {
+        let c = Drawables::Csg(Csg::new(
+            CsgOperation::Union,
+            Drawables::Sphere(Sphere::new()),
+            Drawables::Cube(Cube::new())
+        ));
+        let r = Ray::new(Tuple::new_point(0., 2., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+
+        assert_eq!(r.intersect(&c), None);
+    }
+
+    #[test]
+    fn ray_hits_csg() {
+        let c = Csg::new(
+            CsgOperation::Union,
+            Drawables::Sphere(Sphere::new()),
+            Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(0., 0., 0.5)))
+        );
+        let d = Drawables::Csg(c.clone());
+        let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect(&d).unwrap();
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 4.);
+        assert_eq!(*xs[0].object, *c.left());
+        assert_eq!(xs[1].t, 6.5);
+        assert_eq!(*xs[1].object, *c.right());
+    }
+
+    #[test]
+    fn identical_children_are_told_apart() {
+        let c = Csg::new(
+            CsgOperation::Intersection,
+            Drawables::Sphere(Sphere::new()),
+            Drawables::Sphere(Sphere::new())
+        );
+        let d = Drawables::Csg(c);
+
+        if let Drawables::Csg(c) = &d {
+            assert!(c.left().includes(c.left()));
+            assert!(!c.left().includes(c.right()));
+            assert!(d.includes(c.right()));
+        }
+    }
+
+    #[test]
+    fn difference_in_world() {
+        // A cube with a sphere carved out of its front face
+        let c = Csg::new(
+            CsgOperation::Difference,
+            Drawables::Cube(Cube::new()),
+            Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(0., 0., -1.)))
+        );
+        let w = World {
+            objects: vec![Drawables::Csg(c)],
+            ..Default::default()
+        };
+
+        let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect_world(&w);
+        let h = hit(&xs).unwrap();
+
+        assert_eq!(h.t, 5.);
+        assert_ne!(w.color_at(r, 1), Color::new(0., 0., 0.));
+    }
+}
@@ -0,0 +1,101 @@
+use super::utils::EPSILON;
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::Drawable;
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cube {
+    pub shape: Shape
+}
+
+#[allow(dead_code)]
+impl Cube {
+    pub fn new() -> Self {
+        let shape: Shape = Default::default();
+        Self {
+            shape
+        }
+    }
+
+    pub fn new_with_transform(transform: Matrix4) -> Self {
+        let shape = Shape::new_with_transform(transform);
+        Self {
+            shape
+        }
+    }
+
+    // Finds where the ray enters and leaves the two planes of a slab
+    // that sits at -1 and 1 along one axis
+    fn check_axis(origin: f64, direction: f64) -> (f64, f64) {
+        let t_min_numerator = -1.0 - origin;
+        let t_max_numerator = 1.0 - origin;
+
+        let (t_min, t_max) = if direction.abs() >= EPSILON {
+            (t_min_numerator / direction, t_max_numerator / direction)
+        } else {
+            (t_min_numerator * f64::INFINITY, t_max_numerator * f64::INFINITY)
+        };
+
+        if t_min > t_max {
+            return (t_max, t_min);
+        }
+        (t_min, t_max)
+    }
+}
+
+impl Drawable for Cube {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let (x_t_min, x_t_max) = Self::check_axis(local_ray.origin.x, local_ray.direction.x);
+        let (y_t_min, y_t_max) = Self::check_axis(local_ray.origin.y, local_ray.direction.y);
+        let (z_t_min, z_t_max) = Self::check_axis(local_ray.origin.z, local_ray.direction.z);
+
+        let t_min = x_t_min.max(y_t_min).max(z_t_min);
+        let t_max = x_t_max.min(y_t_max).min(z_t_max);
+
+        if t_min > t_max {
+            return None;
+        }
+
+        Some(vec![t_min, t_max])
+    }
+
+    fn local_normal_at(&self, local_point: Tuple, _: &Intersection) -> Tuple {
+        let abs_x = local_point.x.abs();
+        let abs_y = local_point.y.abs();
+        let abs_z = local_point.z.abs();
+        let max_c = abs_x.max(abs_y).max(abs_z);
+
+        if max_c == abs_x {
+            return Tuple::new_vector(local_point.x, 0., 0.);
+        } else if max_c == abs_y {
+            return Tuple::new_vector(0., local_point.y, 0.);
+        }
+        Tuple::new_vector(0., 0., local_point.z)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Tuple::new_point(-1., -1., -1.), Tuple::new_point(1., 1., 1.))
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+
+impl Default for Cube {
+    fn default() -> Self {
+        Self::new()
+    }
+}
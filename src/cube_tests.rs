@@ -0,0 +1,89 @@
+#[cfg(test)]
+
+mod cube_tests {
+    use crate::cube::*;
+    use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
+    use crate::ray::Ray;
+    use crate::matrix::Matrix4;
+    use crate::generics::{Drawable, Drawables};
+
+    #[test]
+    fn ray_intersects_cube() {
+        let c = Cube::new();
+        let cases = [
+            (Tuple::new_point(5., 0.5, 0.), Tuple::new_vector(-1., 0., 0.), 4., 6.),
+            (Tuple::new_point(-5., 0.5, 0.), Tuple::new_vector(1., 0., 0.), 4., 6.),
+            (Tuple::new_point(0.5, 5., 0.), Tuple::new_vector(0., -1., 0.), 4., 6.),
+            (Tuple::new_point(0.5, -5., 0.), Tuple::new_vector(0., 1., 0.), 4., 6.),
+            (Tuple::new_point(0.5, 0., 5.), Tuple::new_vector(0., 0., -1.), 4., 6.),
+            (Tuple::new_point(0.5, 0., -5.), Tuple::new_vector(0., 0., 1.), 4., 6.),
+            (Tuple::new_point(0., 0.5, 0.), Tuple::new_vector(0., 0., 1.), -1., 1.),
+        ];
+
+        for (origin, direction, t1, t2) in cases.iter() {
+            let r = Ray::new(*origin, *direction).unwrap();
+            let xs = c.local_intersect(r).unwrap();
+            assert_eq!(xs[0], *t1);
+            assert_eq!(xs[1], *t2);
+        }
+    }
+
+    #[test]
+    fn ray_misses_cube() {
+        let c = Cube::new();
+        let cases = [
+            (Tuple::new_point(-2., 0., 0.), Tuple::new_vector(0.2673, 0.5345, 0.8018)),
+            (Tuple::new_point(0., -2., 0.), Tuple::new_vector(0.8018, 0.2673, 0.5345)),
+            (Tuple::new_point(0., 0., -2.), Tuple::new_vector(0.5345, 0.8018, 0.2673)),
+            (Tuple::new_point(2., 0., 2.), Tuple::new_vector(0., 0., -1.)),
+            (Tuple::new_point(0., 2., 2.), Tuple::new_vector(0., -1., 0.)),
+            (Tuple::new_point(2., 2., 0.), Tuple::new_vector(-1., 0., 0.)),
+        ];
+
+        for (origin, direction) in cases.iter() {
+            let r = Ray::new(*origin, *direction).unwrap();
+            assert_eq!(c.local_intersect(r), None);
+        }
+    }
+
+    #[test]
+    fn normal_on_surface_of_cube() {
+        let c = Cube::new();
+        let d = Drawables::Cube(c.clone());
+        let i = Intersection::new(0., &d);
+        let cases = [
+            (Tuple::new_point(1., 0.5, -0.8), Tuple::new_vector(1., 0., 0.)),
+            (Tuple::new_point(-1., -0.2, 0.9), Tuple::new_vector(-1., 0., 0.)),
+            (Tuple::new_point(-0.4, 1., -0.1), Tuple::new_vector(0., 1., 0.)),
+            (Tuple::new_point(0.3, -1., -0.7), Tuple::new_vector(0., -1., 0.)),
+            (Tuple::new_point(-0.6, 0.3, 1.), Tuple::new_vector(0., 0., 1.)),
+            (Tuple::new_point(0.4, 0.4, -1.), Tuple::new_vector(0., 0., -1.)),
+            (Tuple::new_point(1., 1., 1.), Tuple::new_vector(1., 0., 0.)),
+            (Tuple::new_point(-1., -1., -1.), Tuple::new_vector(-1., 0., 0.)),
+        ];
+
+        for (point, normal) in cases.iter() {
+            assert_eq!(c.local_normal_at(*point, &i), *normal);
+        }
+    }
+
+    #[test]
+    fn intersect_transformed_cube() {
+        let c = Drawables::Cube(Cube::new_with_transform(Matrix4::new_translation(0., 0., 1.)));
+        let r = Ray::new(Tuple::new_point(0.5, 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect(&c).unwrap();
+
+        assert_eq!(xs[0].t, 5.);
+        assert_eq!(xs[1].t, 7.);
+        assert_eq!(*xs[0].object, c);
+    }
+
+    #[test]
+    fn world_normal_scaled_cube() {
+        let c = Drawables::Cube(Cube::new_with_transform(Matrix4::new_scaling(2., 2., 2.)));
+        let i = Intersection::new(0., &c);
+        let n = c.normal_at(Tuple::new_point(2., 0.5, 0.), &i);
+        assert_eq!(n, Some(Tuple::new_vector(1., 0., 0.)));
+    }
+}
@@ -0,0 +1,140 @@
+use super::utils::EPSILON;
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::Drawable;
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cylinder {
+    pub shape: Shape,
+    pub minimum: f64,
+    pub maximum: f64,
+    pub closed: bool,
+}
+
+#[allow(dead_code)]
+impl Cylinder {
+    pub fn new() -> Self {
+        let shape: Shape = Default::default();
+        Self {
+            shape,
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn new_with_transform(transform: Matrix4) -> Self {
+        let shape = Shape::new_with_transform(transform);
+        Self {
+            shape,
+            ..Self::new()
+        }
+    }
+
+    // Checks if the intersection at t is within a radius of 1 from the y axis
+    fn check_cap(ray: Ray, t: f64) -> bool {
+        let x = ray.origin.x + t * ray.direction.x;
+        let z = ray.origin.z + t * ray.direction.z;
+
+        (x.powf(2.) + z.powf(2.)) <= 1.0
+    }
+
+    fn intersect_caps(&self, ray: Ray, xs: &mut Vec<f64>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        let t = (self.minimum - ray.origin.y) / ray.direction.y;
+        if Self::check_cap(ray, t) {
+            xs.push(t);
+        }
+
+        let t = (self.maximum - ray.origin.y) / ray.direction.y;
+        if Self::check_cap(ray, t) {
+            xs.push(t);
+        }
+    }
+}
+
+impl Drawable for Cylinder {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let mut xs: Vec<f64> = vec![];
+
+        let a = local_ray.direction.x.powf(2.) + local_ray.direction.z.powf(2.);
+
+        // A ray parallel to the y axis can only hit the caps
+        if a.abs() >= EPSILON {
+            let b = 2. * local_ray.origin.x * local_ray.direction.x +
+                    2. * local_ray.origin.z * local_ray.direction.z;
+            let c = local_ray.origin.x.powf(2.) + local_ray.origin.z.powf(2.) - 1.;
+
+            let discriminant = b.powf(2.) - 4. * a * c;
+            if discriminant < 0. {
+                return None;
+            }
+
+            let mut t0 = (-b - discriminant.sqrt()) / (2. * a);
+            let mut t1 = (-b + discriminant.sqrt()) / (2. * a);
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            let y0 = local_ray.origin.y + t0 * local_ray.direction.y;
+            if self.minimum < y0 && y0 < self.maximum {
+                xs.push(t0);
+            }
+
+            let y1 = local_ray.origin.y + t1 * local_ray.direction.y;
+            if self.minimum < y1 && y1 < self.maximum {
+                xs.push(t1);
+            }
+        }
+
+        self.intersect_caps(local_ray, &mut xs);
+
+        if xs.is_empty() {
+            return None;
+        }
+        Some(xs)
+    }
+
+    fn local_normal_at(&self, local_point: Tuple, _: &Intersection) -> Tuple {
+        let distance = local_point.x.powf(2.) + local_point.z.powf(2.);
+
+        if distance < 1. && local_point.y >= self.maximum - EPSILON {
+            return Tuple::new_vector(0., 1., 0.);
+        } else if distance < 1. && local_point.y <= self.minimum + EPSILON {
+            return Tuple::new_vector(0., -1., 0.);
+        }
+        Tuple::new_vector(local_point.x, 0., local_point.z)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Tuple::new_point(-1., self.minimum, -1.),
+            Tuple::new_point(1., self.maximum, 1.)
+        )
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
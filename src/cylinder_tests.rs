@@ -0,0 +1,158 @@
+#[cfg(test)]
+
+mod cylinder_tests {
+    use crate::cylinder::*;
+    use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
+    use crate::ray::Ray;
+    use crate::color::Color;
+    use crate::world::World;
+    use crate::generics::{Drawable, Drawables};
+    use crate::utils::is_equal;
+
+    #[test]
+    fn ray_misses_cylinder() {
+        let c = Cylinder::new();
+        let cases = [
+            (Tuple::new_point(1., 0., 0.), Tuple::new_vector(0., 1., 0.)),
+            (Tuple::new_point(0., 0., 0.), Tuple::new_vector(0., 1., 0.)),
+            (Tuple::new_point(0., 0., -5.), Tuple::new_vector(1., 1., 1.)),
+        ];
+
+        for (origin, direction) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            assert_eq!(c.local_intersect(r), None);
+        }
+    }
+
+    #[test]
+    fn ray_strikes_cylinder() {
+        let c = Cylinder::new();
+        let cases = [
+            (Tuple::new_point(1., 0., -5.), Tuple::new_vector(0., 0., 1.), 5., 5.),
+            (Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.), 4., 6.),
+            (Tuple::new_point(0.5, 0., -5.), Tuple::new_vector(0.1, 1., 1.), 6.80798, 7.08872),
+        ];
+
+        for (origin, direction, t0, t1) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            let xs = c.local_intersect(r).unwrap();
+            assert!(is_equal(xs[0], *t0));
+            assert!(is_equal(xs[1], *t1));
+        }
+    }
+
+    #[test]
+    fn normal_on_cylinder() {
+        let c = Cylinder::new();
+        let d = Drawables::Cylinder(c.clone());
+        let i = Intersection::new(0., &d);
+        let cases = [
+            (Tuple::new_point(1., 0., 0.), Tuple::new_vector(1., 0., 0.)),
+            (Tuple::new_point(0., 5., -1.), Tuple::new_vector(0., 0., -1.)),
+            (Tuple::new_point(0., -2., 1.), Tuple::new_vector(0., 0., 1.)),
+            (Tuple::new_point(-1., 1., 0.), Tuple::new_vector(-1., 0., 0.)),
+        ];
+
+        for (point, normal) in cases.iter() {
+            assert_eq!(c.local_normal_at(*point, &i), *normal);
+        }
+    }
+
+    #[test]
+    fn default_cylinder_bounds() {
+        let c = Cylinder::new();
+        assert_eq!(c.minimum, f64::NEG_INFINITY);
+        assert_eq!(c.maximum, f64::INFINITY);
+        assert!(!c.closed);
+    }
+
+    #[test]
+    fn intersect_constrained_cylinder() {
+        let mut c = Cylinder::new();
+        c.minimum = 1.;
+        c.maximum = 2.;
+
+        let cases = [
+            (Tuple::new_point(0., 1.5, 0.), Tuple::new_vector(0.1, 1., 0.), 0),
+            (Tuple::new_point(0., 3., -5.), Tuple::new_vector(0., 0., 1.), 0),
+            (Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.), 0),
+            (Tuple::new_point(0., 2., -5.), Tuple::new_vector(0., 0., 1.), 0),
+            (Tuple::new_point(0., 1., -5.), Tuple::new_vector(0., 0., 1.), 0),
+            (Tuple::new_point(0., 1.5, -2.), Tuple::new_vector(0., 0., 1.), 2),
+        ];
+
+        for (origin, direction, count) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            let xs = c.local_intersect(r);
+            let found = xs.map_or(0, |xs| xs.len());
+            assert_eq!(found, *count);
+        }
+    }
+
+    #[test]
+    fn intersect_caps_of_closed_cylinder() {
+        let mut c = Cylinder::new();
+        c.minimum = 1.;
+        c.maximum = 2.;
+        c.closed = true;
+
+        let cases = [
+            (Tuple::new_point(0., 3., 0.), Tuple::new_vector(0., -1., 0.), 2),
+            (Tuple::new_point(0., 3., -2.), Tuple::new_vector(0., -1., 2.), 2),
+            (Tuple::new_point(0., 4., -2.), Tuple::new_vector(0., -1., 1.), 2),
+            (Tuple::new_point(0., 0., -2.), Tuple::new_vector(0., 1., 2.), 2),
+            (Tuple::new_point(0., -1., -2.), Tuple::new_vector(0., 1., 1.), 2),
+        ];
+
+        for (origin, direction, count) in cases.iter() {
+            let r = Ray::new(*origin, direction.normalize()).unwrap();
+            let xs = c.local_intersect(r);
+            let found = xs.map_or(0, |xs| xs.len());
+            assert_eq!(found, *count);
+        }
+    }
+
+    #[test]
+    fn normal_on_cylinder_end_caps() {
+        let mut c = Cylinder::new();
+        c.minimum = 1.;
+        c.maximum = 2.;
+        c.closed = true;
+        let d = Drawables::Cylinder(c.clone());
+        let i = Intersection::new(0., &d);
+
+        let cases = [
+            (Tuple::new_point(0., 1., 0.), Tuple::new_vector(0., -1., 0.)),
+            (Tuple::new_point(0.5, 1., 0.), Tuple::new_vector(0., -1., 0.)),
+            (Tuple::new_point(0., 1., 0.5), Tuple::new_vector(0., -1., 0.)),
+            (Tuple::new_point(0., 2., 0.), Tuple::new_vector(0., 1., 0.)),
+            (Tuple::new_point(0.5, 2., 0.), Tuple::new_vector(0., 1., 0.)),
+            (Tuple::new_point(0., 2., 0.5), Tuple::new_vector(0., 1., 0.)),
+        ];
+
+        for (point, normal) in cases.iter() {
+            assert_eq!(c.local_normal_at(*point, &i), *normal);
+        }
+    }
+
+    #[test]
+    fn cylinder_in_world_casts_shadow() {
+        let mut c = Cylinder::new();
+        c.minimum = -1.;
+        c.maximum = 1.;
+        c.closed = true;
+
+        let w = World {
+            objects: vec![Drawables::Cylinder(c)],
+            ..Default::default()
+        };
+
+        let light_position = Tuple::new_point(-10., 10., -10.);
+        assert!(!w.is_shadowed(Tuple::new_point(0., -5., 0.), light_position));
+        assert!(w.is_shadowed(Tuple::new_point(10., -10., 10.), light_position));
+
+        let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        assert_ne!(w.color_at(r, 1), Color::new(0., 0., 0.));
+    }
+}
@@ -0,0 +1,21 @@
+use super::tuple::Tuple;
+use super::color::Color;
+
+// A light so far away that it shines the same way everywhere, like the sun.
+// The direction is the way the light travels, not where it comes from.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DirectionalLight {
+    pub direction: Tuple,
+    pub intensity: Color,
+}
+
+#[allow(dead_code)]
+impl DirectionalLight {
+    pub fn new(direction: Tuple, intensity: Color) -> Result<Self, &'static str> {
+        if direction.is_point() || direction.magnitude() == 0. {
+            Err("Direction has to be a vector with a length")
+        } else {
+            Ok(Self{direction: direction.normalize(), intensity})
+        }
+    }
+}
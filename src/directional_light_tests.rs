@@ -0,0 +1,24 @@
+#[cfg(test)]
+
+mod directional_light_tests {
+    use crate::directional_light::*;
+    use crate::color::Color;
+    use crate::tuple::Tuple;
+
+    #[test]
+    fn directional_light_with_direction_intensity() {
+        let intensity = Color::new(1., 1., 1.);
+        let light = DirectionalLight::new(Tuple::new_vector(0., -3., 0.), intensity).unwrap();
+
+        assert_eq!(light.direction, Tuple::new_vector(0., -1., 0.));
+        assert_eq!(light.intensity, intensity);
+    }
+
+    #[test]
+    fn direction_has_to_be_a_vector() {
+        let intensity = Color::new(1., 1., 1.);
+
+        assert!(DirectionalLight::new(Tuple::new_point(0., -1., 0.), intensity).is_err());
+        assert!(DirectionalLight::new(Tuple::new_vector(0., 0., 0.), intensity).is_err());
+    }
+}
@@ -1,70 +1,156 @@
 use super::sphere::Sphere;
 use super::plane::Plane;
+use super::cube::Cube;
+use super::cylinder::Cylinder;
+use super::cone::Cone;
+use super::triangle::Triangle;
+use super::smooth_triangle::SmoothTriangle;
+use super::group::Group;
+use super::csg::Csg;
 use super::ray::Ray;
 use super::tuple::Tuple;
 use super::intersection::Intersection;
 use super::matrix::Matrix4;
 use super::shape::Shape;
+use super::bounds::BoundingBox;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Drawables {
     Sphere(Sphere),
     Plane(Plane),
+    Cube(Cube),
+    Cylinder(Cylinder),
+    Cone(Cone),
+    Triangle(Triangle),
+    SmoothTriangle(SmoothTriangle),
+    Group(Group),
+    Csg(Csg),
 }
 
 impl Drawables {
-    pub fn intersect(&self, ray: Ray) -> Option<[Option<Intersection>; 2]> {
-        let ts = self.local_intersect(ray)?;
-
-        let i1 = match ts[0] {
-            Some(t) => Some(Intersection::new(t, self)),
-            None => None,
-        };
+    pub fn intersect(&self, ray: Ray) -> Option<Vec<Intersection>> {
+        // Triangles also keep track of where on their face they were hit
+        match self {
+            Drawables::Triangle(triangle) => {
+                let (t, u, v) = triangle.local_intersect_uv(ray)?;
+                return Some(vec![Intersection::new_with_uv(t, self, u, v)]);
+            },
+            Drawables::SmoothTriangle(triangle) => {
+                let (t, u, v) = triangle.local_intersect_uv(ray)?;
+                return Some(vec![Intersection::new_with_uv(t, self, u, v)]);
+            },
+            // Groups hand back the intersections of their children
+            Drawables::Group(group) => return group.intersect_children(ray),
+            Drawables::Csg(csg) => return csg.intersect_children(ray),
+            _ => (),
+        }
 
-        let i2 = match ts[1] {
-            Some(t) => Some(Intersection::new(t, self)),
-            None => None,
-        };
+        let ts = self.local_intersect(ray)?;
 
-        Some([i1, i2])
+        Some(ts.iter().map(|t| Intersection::new(*t, self)).collect())
     }
 
-    pub fn normal_at(&self, world_point: Tuple) -> Option<Tuple> {
+    pub fn normal_at(&self, world_point: Tuple, hit: &Intersection) -> Option<Tuple> {
         if world_point.is_vector() {
             return None;
         }
 
         let object_shape = self.get_shape();
-        let object_point = object_shape.get_object_point(world_point)?;
-        let object_normal = self.local_normal_at(object_point);
-        let mut world_normal = object_shape.get_world_normal(object_normal)?;
-        world_normal.w = 0.0;
+        let object_point = object_shape.world_to_object(world_point)?;
+        let object_normal = self.local_normal_at(object_point, hit);
+
+        object_shape.normal_to_world(object_normal)
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        match self {
+            Drawables::Group(g) => g.set_transform(transform),
+            Drawables::Csg(c) => c.set_transform(transform),
+            _ => self.get_shape_mut().set_transform(transform),
+        }
+    }
+
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        match self {
+            Drawables::Group(g) => g.set_parent_transform(parent_transform),
+            Drawables::Csg(c) => c.set_parent_transform(parent_transform),
+            _ => self.get_shape_mut().set_parent_transform(parent_transform),
+        }
+    }
+
+    // The bounds of this drawable in the space of whatever contains it
+    pub fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.get_transform())
+    }
+
+    pub fn divide(&mut self, threshold: usize) {
+        match self {
+            Drawables::Group(g) => g.divide(threshold),
+            Drawables::Csg(c) => c.divide(threshold),
+            _ => (),
+        }
+    }
 
-        Some(world_normal.normalize())
+    // Whether other is this drawable, or is somewhere inside of it
+    pub fn includes(&self, other: &Drawables) -> bool {
+        match self {
+            Drawables::Group(g) => g.children().iter().any(|c| c.includes(other)),
+            Drawables::Csg(c) => c.left().includes(other) || c.right().includes(other),
+            _ => std::ptr::eq(self, other),
+        }
     }
 }
 
 pub trait Drawable {
-    fn local_intersect(&self, local_ray: Ray, ) -> Option<[Option<f64>; 2]>;
-    fn local_normal_at(&self, local_point: Tuple) -> Tuple;
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>>;
+    fn local_normal_at(&self, local_point: Tuple, hit: &Intersection) -> Tuple;
+    fn bounds(&self) -> BoundingBox;
     fn get_transform(&self) -> Matrix4;
-    fn get_shape(&self) -> Shape;
+    fn get_shape(&self) -> &Shape;
+    fn get_shape_mut(&mut self) -> &mut Shape;
 }
 
 impl Drawable for Drawables {
-    // NOTE: This doesn't really have to be a nested optional
-    // could just return an array of 2 None values of misses
-    fn local_intersect(&self, local_ray: Ray) -> Option<[Option<f64>; 2]> {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
         match self {
             Drawables::Sphere(s) => s.local_intersect(local_ray),
             Drawables::Plane(p) => p.local_intersect(local_ray),
+            Drawables::Cube(c) => c.local_intersect(local_ray),
+            Drawables::Cylinder(c) => c.local_intersect(local_ray),
+            Drawables::Cone(c) => c.local_intersect(local_ray),
+            Drawables::Triangle(t) => t.local_intersect(local_ray),
+            Drawables::SmoothTriangle(t) => t.local_intersect(local_ray),
+            Drawables::Group(g) => g.local_intersect(local_ray),
+            Drawables::Csg(c) => c.local_intersect(local_ray),
+        }
+    }
+
+    fn local_normal_at(&self, local_point: Tuple, hit: &Intersection) -> Tuple {
+        match self {
+            Drawables::Sphere(s) => s.local_normal_at(local_point, hit),
+            Drawables::Plane(p) => p.local_normal_at(local_point, hit),
+            Drawables::Cube(c) => c.local_normal_at(local_point, hit),
+            Drawables::Cylinder(c) => c.local_normal_at(local_point, hit),
+            Drawables::Cone(c) => c.local_normal_at(local_point, hit),
+            Drawables::Triangle(t) => t.local_normal_at(local_point, hit),
+            Drawables::SmoothTriangle(t) => t.local_normal_at(local_point, hit),
+            Drawables::Group(g) => g.local_normal_at(local_point, hit),
+            Drawables::Csg(c) => c.local_normal_at(local_point, hit),
         }
     }
 
-    fn local_normal_at(&self, local_point: Tuple) -> Tuple {
+    fn bounds(&self) -> BoundingBox {
         match self {
-            Drawables::Sphere(s) => s.local_normal_at(local_point),
-            Drawables::Plane(p) => p.local_normal_at(local_point),
+            Drawables::Sphere(s) => s.bounds(),
+            Drawables::Plane(p) => p.bounds(),
+            Drawables::Cube(c) => c.bounds(),
+            Drawables::Cylinder(c) => c.bounds(),
+            Drawables::Cone(c) => c.bounds(),
+            Drawables::Triangle(t) => t.bounds(),
+            Drawables::SmoothTriangle(t) => t.bounds(),
+            Drawables::Group(g) => g.bounds(),
+            Drawables::Csg(c) => c.bounds(),
         }
     }
 
@@ -72,13 +158,41 @@ impl Drawable for Drawables {
         match self {
             Drawables::Sphere(s) => s.get_transform(),
             Drawables::Plane(p) => p.get_transform(),
+            Drawables::Cube(c) => c.get_transform(),
+            Drawables::Cylinder(c) => c.get_transform(),
+            Drawables::Cone(c) => c.get_transform(),
+            Drawables::Triangle(t) => t.get_transform(),
+            Drawables::SmoothTriangle(t) => t.get_transform(),
+            Drawables::Group(g) => g.get_transform(),
+            Drawables::Csg(c) => c.get_transform(),
         }
     }
 
-    fn get_shape(&self) -> Shape {
-         match self {
+    fn get_shape(&self) -> &Shape {
+        match self {
             Drawables::Sphere(s) => s.get_shape(),
             Drawables::Plane(p) => p.get_shape(),
+            Drawables::Cube(c) => c.get_shape(),
+            Drawables::Cylinder(c) => c.get_shape(),
+            Drawables::Cone(c) => c.get_shape(),
+            Drawables::Triangle(t) => t.get_shape(),
+            Drawables::SmoothTriangle(t) => t.get_shape(),
+            Drawables::Group(g) => g.get_shape(),
+            Drawables::Csg(c) => c.get_shape(),
+        }
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        match self {
+            Drawables::Sphere(s) => s.get_shape_mut(),
+            Drawables::Plane(p) => p.get_shape_mut(),
+            Drawables::Cube(c) => c.get_shape_mut(),
+            Drawables::Cylinder(c) => c.get_shape_mut(),
+            Drawables::Cone(c) => c.get_shape_mut(),
+            Drawables::Triangle(t) => t.get_shape_mut(),
+            Drawables::SmoothTriangle(t) => t.get_shape_mut(),
+            Drawables::Group(g) => g.get_shape_mut(),
+            Drawables::Csg(c) => c.get_shape_mut(),
         }
     }
 }
@@ -0,0 +1,194 @@
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::{Drawable, Drawables};
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
+
+// A collection of drawables that are transformed together.
+// The children are only reachable through `add_child` and `children`, and the
+// transform is changed through `set_transform`, so that the children know
+// about their parents and the bounds of the group stay up to date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub shape: Shape,
+    children: Vec<Drawables>,
+    bounds: BoundingBox,
+}
+
+#[allow(dead_code)]
+impl Group {
+    pub fn new() -> Self {
+        let shape: Shape = Default::default();
+        Self {
+            shape,
+            children: vec![],
+            bounds: BoundingBox::empty(),
+        }
+    }
+
+    pub fn new_with_transform(transform: Matrix4) -> Self {
+        let shape = Shape::new_with_transform(transform);
+        Self {
+            shape,
+            children: vec![],
+            bounds: BoundingBox::empty(),
+        }
+    }
+
+    pub fn add_child(&mut self, mut child: Drawables) {
+        child.set_parent_transform(self.shape.get_world_transform());
+        self.bounds.merge(child.parent_space_bounds());
+        self.children.push(child);
+    }
+
+    pub fn children(&self) -> &[Drawables] {
+        &self.children
+    }
+
+    pub fn into_children(self) -> Vec<Drawables> {
+        self.children
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.shape.set_transform(transform);
+        self.update_children();
+    }
+
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.shape.set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.shape.get_world_transform();
+        for child in self.children.iter_mut() {
+            child.set_parent_transform(world_transform);
+        }
+    }
+
+    // Takes out the children that fit entirely inside either half of the
+    // bounds of this group. Anything straddling the middle stays put.
+    pub fn partition_children(&mut self) -> (Vec<Drawables>, Vec<Drawables>) {
+        // Unbounded children like planes can never be moved into a half,
+        // so they shouldn't decide where the middle is either
+        let mut finite_bounds = BoundingBox::empty();
+        for child in self.children.iter() {
+            let child_bounds = child.parent_space_bounds();
+            if child_bounds.is_finite() {
+                finite_bounds.merge(child_bounds);
+            }
+        }
+        let (left_bounds, right_bounds) = finite_bounds.split();
+
+        let mut left = vec![];
+        let mut right = vec![];
+        let mut remaining = vec![];
+
+        for child in self.children.drain(..) {
+            let child_bounds = child.parent_space_bounds();
+            if left_bounds.contains_box(child_bounds) {
+                left.push(child);
+            } else if right_bounds.contains_box(child_bounds) {
+                right.push(child);
+            } else {
+                remaining.push(child);
+            }
+        }
+
+        self.children = remaining;
+        (left, right)
+    }
+
+    pub fn make_subgroup(&mut self, children: Vec<Drawables>) {
+        let mut subgroup = Group::new();
+        for child in children {
+            subgroup.add_child(child);
+        }
+        self.add_child(Drawables::Group(subgroup));
+    }
+
+    // Builds a bounding volume hierarchy by splitting the children
+    // into nested groups until there are at most threshold in each
+    pub fn divide(&mut self, threshold: usize) {
+        if threshold <= self.children.len() {
+            let count = self.children.len();
+            let (left, right) = self.partition_children();
+
+            // Splitting didn't separate anything, so a subgroup would only be
+            // split the same way forever. The children can still be divided.
+            if left.len() == count || right.len() == count {
+                self.children = if left.is_empty() { right } else { left };
+            } else {
+                if !left.is_empty() {
+                    self.make_subgroup(left);
+                }
+                if !right.is_empty() {
+                    self.make_subgroup(right);
+                }
+            }
+        }
+
+        for child in self.children.iter_mut() {
+            child.divide(threshold);
+        }
+    }
+
+    // The ray is expected to already be in the space of this group
+    pub fn intersect_children(&self, local_ray: Ray) -> Option<Vec<Intersection<'_>>> {
+        if !self.bounds.intersects(local_ray) {
+            return None;
+        }
+
+        let mut xs: Vec<Intersection> = vec![];
+
+        for child in self.children.iter() {
+            if let Some(intersections) = local_ray.intersect(child) {
+                xs.extend(intersections);
+            }
+        }
+
+        if xs.is_empty() {
+            return None;
+        }
+
+        xs.sort();
+        Some(xs)
+    }
+}
+
+impl Drawable for Group {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let xs = self.intersect_children(local_ray)?;
+        Some(xs.iter().map(|i| i.t).collect())
+    }
+
+    // Intersections are always with one of the children, never the group itself,
+    // so there is no normal to be found here
+    fn local_normal_at(&self, _: Tuple, _: &Intersection) -> Tuple {
+        unreachable!("Groups don't have normals, their children do")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Self::new()
+    }
+}
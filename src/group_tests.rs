@@ -0,0 +1,297 @@
+#[cfg(test)]
+
+mod group_tests {
+    use std::f64::consts::PI;
+    use crate::group::*;
+    use crate::sphere::Sphere;
+    use crate::tuple::Tuple;
+    use crate::color::Color;
+    use crate::matrix::Matrix4;
+    use crate::ray::Ray;
+    use crate::intersection::Intersection;
+    use crate::patterns::Patterns;
+    use crate::world::World;
+    use crate::generics::{Drawable, Drawables};
+
+    // A sphere inside a scaled group, inside a rotated group
+    fn nested_sphere(scaling: Matrix4) -> Drawables {
+        let mut g2 = Group::new_with_transform(scaling);
+        g2.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(5., 0., 0.))));
+
+        let mut g1 = Group::new_with_transform(Matrix4::new_rotation_y(PI / 2.));
+        g1.add_child(Drawables::Group(g2));
+
+        match &g1.children()[0] {
+            Drawables::Group(g2) => g2.children()[0].clone(),
+            _ => panic!("Expected a group"),
+        }
+    }
+
+    #[test]
+    fn create_group() {
+        let g = Group::new();
+
+        assert_eq!(g.shape.get_transform(), Matrix4::new_identity());
+        assert!(g.children().is_empty());
+    }
+
+    #[test]
+    fn add_child_to_group() {
+        let mut g = Group::new_with_transform(Matrix4::new_translation(1., 0., 0.));
+        let s = Drawables::Sphere(Sphere::new());
+        g.add_child(s);
+
+        assert_eq!(g.children().len(), 1);
+        assert_eq!(g.children()[0].get_shape().get_parent_transform(), Matrix4::new_translation(1., 0., 0.));
+    }
+
+    #[test]
+    fn set_transform_updates_children() {
+        let mut g = Group::new();
+        g.add_child(Drawables::Sphere(Sphere::new()));
+        g.set_transform(Matrix4::new_scaling(2., 2., 2.));
+
+        assert_eq!(g.children()[0].get_shape().get_parent_transform(), Matrix4::new_scaling(2., 2., 2.));
+    }
+
+    #[test]
+    fn intersect_empty_group() {
+        let g = Drawables::Group(Group::new());
+        let r = Ray::new(Tuple::new_point(0., 0., 0.), Tuple::new_vector(0., 0., 1.)).unwrap();
+
+        assert_eq!(r.intersect(&g), None);
+    }
+
+    #[test]
+    fn intersect_non_empty_group() {
+        let s1 = Drawables::Sphere(Sphere::new());
+        let s2 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(0., 0., -3.)));
+        let s3 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(5., 0., 0.)));
+
+        let mut g = Group::new();
+        g.add_child(s1.clone());
+        g.add_child(s2.clone());
+        g.add_child(s3);
+        let g = Drawables::Group(g);
+
+        let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect(&g).unwrap();
+
+        assert_eq!(xs.len(), 4);
+        assert_eq!(*xs[0].object, s2);
+        assert_eq!(*xs[1].object, s2);
+        assert_eq!(*xs[2].object, s1);
+        assert_eq!(*xs[3].object, s1);
+    }
+
+    #[test]
+    fn intersect_transformed_group() {
+        let mut g = Group::new_with_transform(Matrix4::new_scaling(2., 2., 2.));
+        g.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(5., 0., 0.))));
+        let g = Drawables::Group(g);
+
+        let r = Ray::new(Tuple::new_point(10., 0., -10.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect(&g).unwrap();
+
+        assert_eq!(xs.len(), 2);
+    }
+
+    #[test]
+    fn world_to_object_through_parents() {
+        let s = nested_sphere(Matrix4::new_scaling(2., 2., 2.));
+        let p = s.get_shape().world_to_object(Tuple::new_point(-2., 0., -10.));
+
+        assert_eq!(p, Some(Tuple::new_point(0., 0., -1.)));
+    }
+
+    #[test]
+    fn normal_to_world_through_parents() {
+        let s = nested_sphere(Matrix4::new_scaling(1., 2., 3.));
+        let n = s.get_shape().normal_to_world(Tuple::new_vector(3_f64.sqrt() / 3., 3_f64.sqrt() / 3., 3_f64.sqrt() / 3.));
+
+        assert_eq!(n, Some(Tuple::new_vector(0.28571, 0.42857, -0.85714)));
+    }
+
+    #[test]
+    fn normal_on_child_object() {
+        let s = nested_sphere(Matrix4::new_scaling(1., 2., 3.));
+        let i = Intersection::new(0., &s);
+        let n = s.normal_at(Tuple::new_point(1.7321, 1.1547, -5.5774), &i);
+
+        assert_eq!(n, Some(Tuple::new_vector(0.28570, 0.42854, -0.85716)));
+    }
+
+    #[test]
+    fn pattern_on_nested_object() {
+        let s = nested_sphere(Matrix4::new_scaling(2., 2., 2.));
+        let pattern = Patterns::new_test();
+        let c = pattern.color_at_object(&s, Tuple::new_point(-2., 0., -10.));
+
+        assert_eq!(c, Some(Color::new(0., 0., -1.)));
+    }
+
+    #[test]
+    fn group_in_world_is_shaded() {
+        let mut g = Group::new_with_transform(Matrix4::new_translation(0., 0., 5.));
+        g.add_child(Drawables::Sphere(Sphere::new()));
+
+        let w = World {
+            objects: vec![Drawables::Group(g)],
+            ..Default::default()
+        };
+
+        let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect_world(&w);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 9.);
+        assert_ne!(w.color_at(r, 1), Color::new(0., 0., 0.));
+    }
+
+    #[test]
+    fn group_bounds_grow_with_children() {
+        let mut g = Group::new();
+        g.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(2., 5., -3.))));
+
+        assert_eq!(g.bounds().min, Tuple::new_point(1., 4., -4.));
+        assert_eq!(g.bounds().max, Tuple::new_point(3., 6., -2.));
+    }
+
+    #[test]
+    fn ray_misses_group_bounds() {
+        let mut g = Group::new();
+        g.add_child(Drawables::Sphere(Sphere::new()));
+        let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 1., 0.)).unwrap();
+
+        assert_eq!(g.intersect_children(r), None);
+    }
+
+    #[test]
+    fn partition_group_children() {
+        let s1 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., 0., 0.)));
+        let s2 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(2., 0., 0.)));
+        let s3 = Drawables::Sphere(Sphere::new());
+        let mut g = Group::new();
+        g.add_child(s1.clone());
+        g.add_child(s2.clone());
+        g.add_child(s3.clone());
+
+        let (left, right) = g.partition_children();
+
+        assert_eq!(g.children(), vec![s3]);
+        assert_eq!(left, vec![s1]);
+        assert_eq!(right, vec![s2]);
+    }
+
+    #[test]
+    fn subdivide_group() {
+        let s1 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., -2., 0.)));
+        let s2 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., 2., 0.)));
+        let s3 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_scaling(4., 4., 4.)));
+        let mut g = Group::new();
+        g.add_child(s1.clone());
+        g.add_child(s2.clone());
+        g.add_child(s3.clone());
+
+        g.divide(1);
+
+        assert_eq!(g.children().len(), 2);
+        assert_eq!(g.children()[0], s3);
+        match &g.children()[1] {
+            Drawables::Group(sub) => {
+                assert_eq!(sub.children().len(), 2);
+                assert_eq!(sub.children()[0], Drawables::Group({
+                    let mut g = Group::new();
+                    g.add_child(s1);
+                    g
+                }));
+                assert_eq!(sub.children()[1], Drawables::Group({
+                    let mut g = Group::new();
+                    g.add_child(s2);
+                    g
+                }));
+            },
+            _ => panic!("expected a subgroup"),
+        }
+    }
+
+    #[test]
+    fn dividing_keeps_group_bounds() {
+        let mut g = Group::new();
+        g.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., -2., 0.))));
+        g.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., 2., 0.))));
+        g.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(3., 0., 0.))));
+        let bounds = g.bounds();
+
+        g.divide(1);
+
+        assert_eq!(g.bounds(), bounds);
+        let r = Ray::new(Tuple::new_point(-2., 2., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        assert_eq!(g.intersect_children(r).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn subdivide_group_with_too_few_children() {
+        let s1 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., 0., 0.)));
+        let s2 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(2., 1., 0.)));
+        let s3 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(2., -1., 0.)));
+        let s4 = Drawables::Sphere(Sphere::new());
+        let mut sub = Group::new();
+        sub.add_child(s1);
+        sub.add_child(s2.clone());
+        sub.add_child(s3.clone());
+        let mut g = Group::new();
+        g.add_child(Drawables::Group(sub));
+        g.add_child(s4.clone());
+
+        g.divide(3);
+
+        assert_eq!(g.children()[1], s4);
+        match &g.children()[0] {
+            Drawables::Group(sub) => {
+                assert_eq!(sub.children().len(), 2);
+                match &sub.children()[1] {
+                    Drawables::Group(right) => assert_eq!(right.children(), vec![s2, s3]),
+                    _ => panic!("expected a subgroup"),
+                }
+            },
+            _ => panic!("expected a subgroup"),
+        }
+    }
+
+    #[test]
+    fn divide_identical_children() {
+        let mut g = Group::new();
+        for _ in 0..4 {
+            g.add_child(Drawables::Sphere(Sphere::new()));
+        }
+
+        g.divide(1);
+
+        assert_eq!(g.children().len(), 4);
+    }
+
+    #[test]
+    fn divide_children_when_nothing_splits() {
+        // Both are squashed down to the same point, so this level can't be
+        // split, but the children of the inner group still can
+        let squash = Matrix4::new_scaling(0., 0., 0.);
+        let mut inner = Group::new_with_transform(squash);
+        inner.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(-2., 0., 0.))));
+        inner.add_child(Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(2., 0., 0.))));
+        let mut g = Group::new();
+        g.add_child(Drawables::Group(inner));
+        g.add_child(Drawables::Sphere(Sphere::new_with_transform(squash)));
+
+        g.divide(1);
+
+        assert_eq!(g.children().len(), 2);
+        match &g.children()[0] {
+            Drawables::Group(inner) => {
+                assert_eq!(inner.children().len(), 2);
+                assert!(inner.children().iter().all(|c| matches!(c, Drawables::Group(_))));
+            },
+            _ => panic!("expected the inner group"),
+        }
+    }
+}
@@ -34,12 +34,17 @@ pub struct Comps<'a> {
 pub struct Intersection<'a> {
     pub t: f64,
     pub object: &'a Drawables,
+    pub uv: Option<(f64, f64)>,
 }
 
 #[allow(dead_code)]
 impl<'a> Intersection<'a> {
     pub fn new(t: f64, object: &'a Drawables) -> Self {
-        Self { t, object }
+        Self { t, object, uv: None }
+    }
+
+    pub fn new_with_uv(t: f64, object: &'a Drawables, u: f64, v: f64) -> Self {
+        Self { t, object, uv: Some((u, v)) }
     }
 
     pub fn prepare_computations(&self, ray: Ray, xs: Option<&Vec<Intersection>>) -> Option<Comps> {
@@ -48,7 +53,7 @@ impl<'a> Intersection<'a> {
         let point = ray.position(self.t);
         let eye_v = -ray.direction;
 
-        let mut normal_v = object.normal_at(point)?;
+        let mut normal_v = object.normal_at(point, self)?;
         let mut inside = false;
         if normal_v.dot(eye_v) < 0.0 {
             inside = true;
@@ -65,7 +70,7 @@ impl<'a> Intersection<'a> {
 
         let mut n1: f64 = 1.0;
         let mut n2: f64 = 1.0;
-        let mut containers: Vec<Drawables> = vec![];
+        let mut containers: Vec<&Drawables> = vec![];
         for i in intersections.iter() {
             if i == self {
                 if containers.is_empty() {
@@ -75,10 +80,10 @@ impl<'a> Intersection<'a> {
                 }
             }
 
-            if let Some(index) = containers.iter().position(|&s| s == *i.object) {
+            if let Some(index) = containers.iter().position(|&s| s == i.object) {
                 containers.remove(index);
             } else {
-                containers.push(*i.object);
+                containers.push(i.object);
             }
 
             if i == self {
@@ -0,0 +1,102 @@
+pub mod tuple;
+mod tuple_tests;
+
+pub mod color;
+mod color_tests;
+
+pub mod canvas;
+mod canvas_tests;
+
+pub mod matrix;
+mod matrix_tests;
+
+pub mod ray;
+mod ray_tests;
+
+pub mod sphere;
+mod sphere_tests;
+
+pub mod plane;
+mod plane_tests;
+
+pub mod cube;
+mod cube_tests;
+
+pub mod cylinder;
+mod cylinder_tests;
+
+pub mod cone;
+mod cone_tests;
+
+pub mod triangle;
+mod triangle_tests;
+
+pub mod smooth_triangle;
+mod smooth_triangle_tests;
+
+pub mod obj_parser;
+mod obj_parser_tests;
+
+pub mod yaml;
+mod yaml_tests;
+
+pub mod scene;
+mod scene_tests;
+
+pub mod cli;
+mod cli_tests;
+
+pub mod group;
+mod group_tests;
+
+pub mod csg;
+mod csg_tests;
+
+pub mod bounds;
+mod bounds_tests;
+
+pub mod intersection;
+
+pub mod point_light;
+mod point_light_tests;
+
+pub mod area_light;
+mod area_light_tests;
+
+pub mod spot_light;
+mod spot_light_tests;
+
+pub mod directional_light;
+mod directional_light_tests;
+
+pub mod lights;
+mod lights_tests;
+
+pub mod material;
+mod material_tests;
+
+pub mod utils;
+mod utils_tests;
+
+pub mod world;
+mod world_tests;
+
+pub mod camera;
+mod camera_tests;
+
+pub mod random;
+mod random_tests;
+
+pub mod shape;
+mod shape_tests;
+
+pub mod patterns;
+mod patterns_tests;
+
+pub mod texture;
+mod texture_tests;
+
+pub mod noise;
+mod noise_tests;
+
+pub mod generics;
@@ -0,0 +1,113 @@
+use super::point_light::PointLight;
+use super::area_light::AreaLight;
+use super::spot_light::SpotLight;
+use super::directional_light::DirectionalLight;
+use super::tuple::Tuple;
+use super::color::Color;
+
+pub trait Light {
+    fn intensity(&self) -> Color;
+    // Where the light reaches the point from. Lights with a size give back
+    // several positions, and the point is lit by the average of them.
+    // Lights that are infinitely far away give back a vector pointing
+    // towards them instead of a point, see to_light.
+    fn positions(&self, point: Tuple) -> Vec<Tuple>;
+    // How strong the light is in the direction of the point, for lights
+    // that don't shine the same way everywhere
+    fn falloff(&self, _point: Tuple) -> f64 {
+        1.
+    }
+}
+
+// The direction from the point towards a position of a light,
+// and how far away it is
+pub fn to_light(point: Tuple, light_position: Tuple) -> (Tuple, f64) {
+    if light_position.is_vector() {
+        return (light_position.normalize(), f64::INFINITY);
+    }
+
+    let v = light_position - point;
+    (v.normalize(), v.magnitude())
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Lights {
+    Point(PointLight),
+    Area(AreaLight),
+    Spot(SpotLight),
+    Directional(DirectionalLight),
+}
+
+impl Light for PointLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn positions(&self, _: Tuple) -> Vec<Tuple> {
+        vec![self.position]
+    }
+}
+
+impl Light for AreaLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn positions(&self, point: Tuple) -> Vec<Tuple> {
+        self.points_on_light(point)
+    }
+}
+
+impl Light for SpotLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn positions(&self, _: Tuple) -> Vec<Tuple> {
+        vec![self.position]
+    }
+
+    fn falloff(&self, point: Tuple) -> f64 {
+        SpotLight::falloff(self, point)
+    }
+}
+
+impl Light for DirectionalLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn positions(&self, _: Tuple) -> Vec<Tuple> {
+        vec![-self.direction]
+    }
+}
+
+impl Light for Lights {
+    fn intensity(&self) -> Color {
+        match self {
+            Lights::Point(light) => light.intensity(),
+            Lights::Area(light) => light.intensity(),
+            Lights::Spot(light) => light.intensity(),
+            Lights::Directional(light) => light.intensity(),
+        }
+    }
+
+    fn positions(&self, point: Tuple) -> Vec<Tuple> {
+        match self {
+            Lights::Point(light) => light.positions(point),
+            Lights::Area(light) => light.positions(point),
+            Lights::Spot(light) => light.positions(point),
+            Lights::Directional(light) => light.positions(point),
+        }
+    }
+
+    fn falloff(&self, point: Tuple) -> f64 {
+        match self {
+            Lights::Point(light) => light.falloff(point),
+            Lights::Area(light) => light.falloff(point),
+            Lights::Spot(light) => light.falloff(point),
+            Lights::Directional(light) => light.falloff(point),
+        }
+    }
+}
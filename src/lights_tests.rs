@@ -0,0 +1,74 @@
+#[cfg(test)]
+
+mod lights_tests {
+    use crate::lights::*;
+    use crate::point_light::PointLight;
+    use crate::area_light::AreaLight;
+    use crate::spot_light::SpotLight;
+    use crate::directional_light::DirectionalLight;
+    use crate::color::Color;
+    use crate::tuple::Tuple;
+
+    #[test]
+    fn point_light_has_one_position() {
+        let position = Tuple::new_point(1., 2., 3.);
+        let light = Lights::Point(PointLight::new(position, Color::new(1., 0.5, 1.)).unwrap());
+
+        assert_eq!(light.positions(Tuple::new_point(0., 0., 0.)), vec![position]);
+        assert_eq!(light.intensity(), Color::new(1., 0.5, 1.));
+    }
+
+    #[test]
+    fn area_light_has_a_position_per_cell() {
+        let light = Lights::Area(AreaLight::new(
+            Tuple::new_point(-1., 0., -1.),
+            Tuple::new_vector(2., 0., 0.),
+            2,
+            Tuple::new_vector(0., 0., 2.),
+            2,
+            Color::new(1., 1., 1.),
+        ).unwrap());
+
+        assert_eq!(light.positions(Tuple::new_point(0., -5., 0.)), vec![
+            Tuple::new_point(-0.5, 0., -0.5),
+            Tuple::new_point(0.5, 0., -0.5),
+            Tuple::new_point(-0.5, 0., 0.5),
+            Tuple::new_point(0.5, 0., 0.5),
+        ]);
+        assert_eq!(light.intensity(), Color::new(1., 1., 1.));
+    }
+
+    #[test]
+    fn only_spot_lights_fall_off() {
+        let point = Tuple::new_point(10., 0., 0.);
+        let spot = Lights::Spot(SpotLight::new(
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(0., -1., 0.),
+            0.5,
+            1.,
+            Color::new(1., 1., 1.),
+        ).unwrap());
+        let point_light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., 0.), Color::new(1., 1., 1.)).unwrap());
+
+        assert_eq!(spot.positions(point), vec![Tuple::new_point(0., 0., 0.)]);
+        assert_eq!(spot.falloff(point), 0.);
+        assert_eq!(point_light.falloff(point), 1.);
+    }
+
+    #[test]
+    fn directional_light_is_infinitely_far_away() {
+        let light = Lights::Directional(DirectionalLight::new(Tuple::new_vector(0., -1., 0.), Color::new(1., 1., 1.)).unwrap());
+        let point = Tuple::new_point(3., 0., 3.);
+
+        assert_eq!(light.positions(point), vec![Tuple::new_vector(0., 1., 0.)]);
+        assert_eq!(light.falloff(point), 1.);
+    }
+
+    #[test]
+    fn direction_and_distance_to_light() {
+        let point = Tuple::new_point(0., 0., 0.);
+
+        assert_eq!(to_light(point, Tuple::new_point(0., 0., -10.)), (Tuple::new_vector(0., 0., -1.), 10.));
+        assert_eq!(to_light(point, Tuple::new_vector(0., 2., 0.)), (Tuple::new_vector(0., 1., 0.), f64::INFINITY));
+    }
+}
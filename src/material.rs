@@ -12,7 +12,7 @@ pub const GLASS: f64 = 1.52;
 #[allow(dead_code)]
 pub const DIAMOND: f64 = 2.417;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Material {
     pub color: Color,
     pub ambient: f64,
@@ -0,0 +1,115 @@
+use super::tuple::Tuple;
+use super::random::Rng;
+
+// How the octaves of noise are added up, see Noise::fractal
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Fractal {
+    // Fractal brownian motion, soft and cloudy
+    Fbm,
+    // The noise folded over at 0, which gives the sharp creases that look
+    // like veins in marble
+    Turbulence,
+}
+
+// Ken Perlin's improved gradient noise. The seed shuffles the permutation
+// table, so the same seed always gives the same noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Noise {
+    // The shuffled numbers 0 to 255 twice, so lookups never have to wrap
+    permutation: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl Noise {
+    pub fn new(seed: u64) -> Self {
+        let mut table: Vec<usize> = (0..256).collect();
+        let mut rng = Rng::new(seed);
+        for i in (1..256).rev() {
+            let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+
+        let mut permutation = table.clone();
+        permutation.extend(table);
+        Self { permutation }
+    }
+
+    // Smooth noise between -1 and 1, which is 0 on every whole coordinate
+    pub fn at(&self, point: Tuple) -> f64 {
+        let (x, y, z) = (point.x, point.y, point.z);
+        let (xi, yi, zi) = (lattice(x), lattice(y), lattice(z));
+        let (x, y, z) = (x - x.floor(), y - y.floor(), z - z.floor());
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let p = &self.permutation;
+        let a = p[xi] + yi;
+        let aa = p[a] + zi;
+        let ab = p[a + 1] + zi;
+        let b = p[xi + 1] + yi;
+        let ba = p[b] + zi;
+        let bb = p[b + 1] + zi;
+
+        lerp(w,
+            lerp(v,
+                lerp(u, gradient(p[aa], x, y, z), gradient(p[ba], x - 1., y, z)),
+                lerp(u, gradient(p[ab], x, y - 1., z), gradient(p[bb], x - 1., y - 1., z))),
+            lerp(v,
+                lerp(u, gradient(p[aa + 1], x, y, z - 1.), gradient(p[ba + 1], x - 1., y, z - 1.)),
+                lerp(u, gradient(p[ab + 1], x, y - 1., z - 1.), gradient(p[bb + 1], x - 1., y - 1., z - 1.))))
+    }
+
+    // Each octave is twice as detailed and half as strong as the one before.
+    // Between -1 and 1 for fbm, and between 0 and 1 for turbulence.
+    pub fn fractal(&self, fractal: Fractal, point: Tuple, octaves: usize) -> f64 {
+        let mut sum = 0.;
+        let mut total = 0.;
+        let mut frequency = 1.;
+        let mut amplitude = 1.;
+
+        for _ in 0..octaves {
+            let p = Tuple::new_point(point.x * frequency, point.y * frequency, point.z * frequency);
+            let n = match fractal {
+                Fractal::Fbm => self.at(p),
+                Fractal::Turbulence => self.at(p).abs(),
+            };
+            sum += n * amplitude;
+            total += amplitude;
+            frequency *= 2.;
+            amplitude /= 2.;
+        }
+
+        if total == 0. {
+            0.
+        } else {
+            sum / total
+        }
+    }
+}
+
+fn lattice(n: f64) -> usize {
+    (n.floor() as i64).rem_euclid(256) as usize
+}
+
+// 6t^5 - 15t^4 + 10t^3, flat at both ends so the cells join up smoothly
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+// Picks one of the 12 directions to the middle of the edges of a cube
+fn gradient(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+
+mod noise_tests {
+    use crate::noise::{Fractal, Noise};
+    use crate::random::Rng;
+    use crate::tuple::Tuple;
+
+    fn random_points(count: usize) -> Vec<Tuple> {
+        let mut rng = Rng::new(3);
+        (0..count).map(|_| {
+            Tuple::new_point(
+                rng.next_f64() * 20. - 10.,
+                rng.next_f64() * 20. - 10.,
+                rng.next_f64() * 20. - 10.,
+            )
+        }).collect()
+    }
+
+    #[test]
+    fn zero_on_whole_coordinates() {
+        let noise = Noise::new(1);
+
+        for point in [
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_point(1., 2., 3.),
+            Tuple::new_point(-4., 7., -300.),
+        ].iter() {
+            assert_eq!(noise.at(*point), 0.);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_noise() {
+        let a = Noise::new(42);
+        let b = Noise::new(42);
+        let c = Noise::new(43);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let points = random_points(100);
+        assert!(points.iter().all(|p| a.at(*p) == b.at(*p)));
+        assert!(points.iter().any(|p| a.at(*p) != c.at(*p)));
+    }
+
+    #[test]
+    fn noise_stays_in_range() {
+        let noise = Noise::new(7);
+        let mut low = 0.;
+        let mut high = 0.;
+
+        for point in random_points(2000).iter() {
+            let n = noise.at(*point);
+            assert!((-1. ..=1.).contains(&n));
+            low = n.min(low);
+            high = n.max(high);
+        }
+
+        // And isn't stuck at 0 between the whole coordinates either
+        assert!(low < -0.3 && high > 0.3);
+    }
+
+    #[test]
+    fn noise_is_smooth() {
+        let noise = Noise::new(7);
+        let step = Tuple::new_vector(0.001, 0.001, 0.001);
+
+        for point in random_points(200).iter() {
+            assert!((noise.at(*point) - noise.at(*point + step)).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn one_octave_is_plain_noise() {
+        let noise = Noise::new(5);
+
+        for point in random_points(50).iter() {
+            assert_eq!(noise.fractal(Fractal::Fbm, *point, 1), noise.at(*point));
+            assert_eq!(noise.fractal(Fractal::Turbulence, *point, 1), noise.at(*point).abs());
+        }
+    }
+
+    #[test]
+    fn fractals_stay_in_range() {
+        let noise = Noise::new(9);
+
+        for point in random_points(500).iter() {
+            assert!((-1. ..=1.).contains(&noise.fractal(Fractal::Fbm, *point, 4)));
+            assert!((0. ..=1.).contains(&noise.fractal(Fractal::Turbulence, *point, 4)));
+        }
+    }
+
+    #[test]
+    fn more_octaves_add_detail() {
+        let noise = Noise::new(9);
+        let point = Tuple::new_point(1.3, 2.7, -0.6);
+
+        assert_ne!(noise.fractal(Fractal::Fbm, point, 1), noise.fractal(Fractal::Fbm, point, 4));
+        assert_eq!(noise.fractal(Fractal::Fbm, point, 0), 0.);
+    }
+}
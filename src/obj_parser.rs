@@ -0,0 +1,227 @@
+use std::fs;
+
+use super::tuple::Tuple;
+use super::triangle::Triangle;
+use super::smooth_triangle::SmoothTriangle;
+use super::generics::Drawables;
+use super::group::Group;
+
+// Reads the subset of Wavefront OBJ that we care about:
+// vertices, vertex normals, texture coordinates, polygonal faces and groups.
+// Anything else is skipped and counted in `ignored`.
+#[derive(Debug, Clone)]
+pub struct ObjParser {
+    pub vertices: Vec<Tuple>,
+    pub normals: Vec<Tuple>,
+    pub texture_coords: Vec<(f64, f64)>,
+    pub default_group: Vec<Drawables>,
+    pub groups: Vec<(String, Vec<Drawables>)>,
+    pub ignored: usize,
+    current_group: Option<usize>,
+}
+
+// The indices of a single face vertex, as in `v/vt/vn`
+#[derive(Debug, Copy, Clone)]
+struct FaceVertex {
+    vertex: Tuple,
+    normal: Option<Tuple>,
+}
+
+#[allow(dead_code)]
+impl ObjParser {
+    pub fn parse(input: &str) -> Self {
+        let mut parser = Self {
+            vertices: vec![],
+            normals: vec![],
+            texture_coords: vec![],
+            default_group: vec![],
+            groups: vec![],
+            ignored: 0,
+            current_group: None,
+        };
+
+        for line in input.lines() {
+            if !parser.parse_line(line) {
+                parser.ignored += 1;
+            }
+        }
+
+        parser
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, &'static str> {
+        match fs::read_to_string(path) {
+            Ok(input) => Ok(Self::parse(&input)),
+            Err(_) => Err("Could not read obj file"),
+        }
+    }
+
+    pub fn get_group(&self, name: &str) -> Option<&Vec<Drawables>> {
+        self.groups.iter().find(|(n, _)| n == name).map(|(_, g)| g)
+    }
+
+    // Every triangle that was read, ready to be pushed into World::objects
+    pub fn to_objects(&self) -> Vec<Drawables> {
+        let mut objects = self.default_group.clone();
+        for (_, group) in self.groups.iter() {
+            objects.extend(group.iter().cloned());
+        }
+        objects
+    }
+
+    // The whole file as a single group, with every named group nested inside it
+    pub fn to_group(&self) -> Group {
+        let mut group = Group::new();
+        for object in self.default_group.iter() {
+            group.add_child(object.clone());
+        }
+
+        for (_, children) in self.groups.iter() {
+            let mut sub_group = Group::new();
+            for child in children.iter() {
+                sub_group.add_child(child.clone());
+            }
+            group.add_child(Drawables::Group(sub_group));
+        }
+
+        group
+    }
+
+    // Returns false if the line was not understood
+    fn parse_line(&mut self, line: &str) -> bool {
+        let mut tokens = line.split_whitespace();
+        let keyword = tokens.next();
+        let args: Vec<&str> = tokens.collect();
+
+        match keyword {
+            Some("v") => {
+                match Self::parse_floats(&args, 3) {
+                    Some(v) => self.vertices.push(Tuple::new_point(v[0], v[1], v[2])),
+                    None => return false,
+                }
+            },
+            Some("vn") => {
+                match Self::parse_floats(&args, 3) {
+                    Some(v) => self.normals.push(Tuple::new_vector(v[0], v[1], v[2])),
+                    None => return false,
+                }
+            },
+            Some("vt") => {
+                match Self::parse_floats(&args, 2) {
+                    Some(v) => self.texture_coords.push((v[0], v[1])),
+                    None => return false,
+                }
+            },
+            Some("f") => return self.parse_face(&args),
+            Some("g") => {
+                if args.is_empty() {
+                    return false;
+                }
+                let name = args.join(" ");
+                match self.groups.iter().position(|(n, _)| *n == name) {
+                    Some(index) => self.current_group = Some(index),
+                    None => {
+                        self.groups.push((name, vec![]));
+                        self.current_group = Some(self.groups.len() - 1);
+                    }
+                }
+            },
+            // Blank lines aren't worth reporting
+            None => (),
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn parse_floats(args: &[&str], count: usize) -> Option<Vec<f64>> {
+        if args.len() < count {
+            return None;
+        }
+
+        args.iter()
+            .take(count)
+            .map(|a| a.parse::<f64>().ok())
+            .collect()
+    }
+
+    // OBJ indices start at 1, and negative ones count from the end
+    fn resolve_index(index: &str, length: usize) -> Option<usize> {
+        let index = index.parse::<i64>().ok()?;
+        if index > 0 && index as usize <= length {
+            return Some(index as usize - 1);
+        }
+        if index < 0 && (-index) as usize <= length {
+            return Some((length as i64 + index) as usize);
+        }
+        None
+    }
+
+    fn parse_face_vertex(&self, arg: &str) -> Option<FaceVertex> {
+        let mut indices = arg.split('/');
+
+        let vertex_index = Self::resolve_index(indices.next()?, self.vertices.len())?;
+        let vertex = self.vertices[vertex_index];
+
+        if let Some(texture_index) = indices.next() {
+            if !texture_index.is_empty() {
+                Self::resolve_index(texture_index, self.texture_coords.len())?;
+            }
+        }
+
+        let normal = match indices.next() {
+            Some(normal_index) if !normal_index.is_empty() => {
+                let normal_index = Self::resolve_index(normal_index, self.normals.len())?;
+                Some(self.normals[normal_index])
+            },
+            _ => None,
+        };
+
+        Some(FaceVertex { vertex, normal })
+    }
+
+    fn parse_face(&mut self, args: &[&str]) -> bool {
+        if args.len() < 3 {
+            return false;
+        }
+
+        let face_vertices: Option<Vec<FaceVertex>> = args.iter()
+            .map(|a| self.parse_face_vertex(a))
+            .collect();
+
+        let face_vertices = match face_vertices {
+            Some(f) => f,
+            None => return false,
+        };
+
+        let smooth = face_vertices.iter().all(|f| f.normal.is_some());
+
+        // Polygons are split into a fan of triangles around the first vertex
+        let mut triangles = vec![];
+        for i in 1..face_vertices.len() - 1 {
+            let a = face_vertices[0];
+            let b = face_vertices[i];
+            let c = face_vertices[i + 1];
+
+            if smooth {
+                triangles.push(Drawables::SmoothTriangle(SmoothTriangle::new(
+                    a.vertex,
+                    b.vertex,
+                    c.vertex,
+                    a.normal.unwrap(),
+                    b.normal.unwrap(),
+                    c.normal.unwrap(),
+                )));
+            } else {
+                triangles.push(Drawables::Triangle(Triangle::new(a.vertex, b.vertex, c.vertex)));
+            }
+        }
+
+        match self.current_group {
+            Some(index) => self.groups[index].1.extend(triangles),
+            None => self.default_group.extend(triangles),
+        }
+
+        true
+    }
+}
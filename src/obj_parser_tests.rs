@@ -0,0 +1,234 @@
+#[cfg(test)]
+
+mod obj_parser_tests {
+    use crate::obj_parser::*;
+    use crate::tuple::Tuple;
+    use crate::color::Color;
+    use crate::ray::Ray;
+    use crate::world::World;
+    use crate::generics::Drawables;
+    use crate::group::Group;
+
+    #[test]
+    fn ignore_unrecognized_lines() {
+        let gibberish = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.";
+
+        let parser = ObjParser::parse(gibberish);
+        assert_eq!(parser.ignored, 5);
+    }
+
+    #[test]
+    fn vertex_records() {
+        let file = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0";
+
+        let parser = ObjParser::parse(file);
+        assert_eq!(parser.ignored, 0);
+        assert_eq!(parser.vertices[0], Tuple::new_point(-1., 1., 0.));
+        assert_eq!(parser.vertices[1], Tuple::new_point(-1., 0.5, 0.));
+        assert_eq!(parser.vertices[2], Tuple::new_point(1., 0., 0.));
+        assert_eq!(parser.vertices[3], Tuple::new_point(1., 1., 0.));
+    }
+
+    #[test]
+    fn malformed_vertex_is_ignored() {
+        let parser = ObjParser::parse("v 1 two 3\nv 1 2");
+        assert_eq!(parser.ignored, 2);
+        assert!(parser.vertices.is_empty());
+    }
+
+    #[test]
+    fn triangle_faces() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4";
+
+        let parser = ObjParser::parse(file);
+        assert_eq!(parser.ignored, 0);
+        assert_eq!(parser.default_group.len(), 2);
+
+        if let (Drawables::Triangle(t1), Drawables::Triangle(t2)) = (&parser.default_group[0], &parser.default_group[1]) {
+            assert_eq!(t1.p1, parser.vertices[0]);
+            assert_eq!(t1.p2, parser.vertices[1]);
+            assert_eq!(t1.p3, parser.vertices[2]);
+            assert_eq!(t2.p1, parser.vertices[0]);
+            assert_eq!(t2.p2, parser.vertices[2]);
+            assert_eq!(t2.p3, parser.vertices[3]);
+        } else {
+            panic!("Expected two triangles");
+        }
+    }
+
+    #[test]
+    fn triangulate_polygons() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5";
+
+        let parser = ObjParser::parse(file);
+        assert_eq!(parser.default_group.len(), 3);
+
+        let expected = [(0, 1, 2), (0, 2, 3), (0, 3, 4)];
+        for (object, (a, b, c)) in parser.default_group.iter().zip(expected.iter()) {
+            if let Drawables::Triangle(t) = object {
+                assert_eq!(t.p1, parser.vertices[*a]);
+                assert_eq!(t.p2, parser.vertices[*b]);
+                assert_eq!(t.p3, parser.vertices[*c]);
+            } else {
+                panic!("Expected a triangle");
+            }
+        }
+    }
+
+    #[test]
+    fn triangles_in_groups() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+
+        let parser = ObjParser::parse(file);
+        let first = parser.get_group("FirstGroup").unwrap();
+        let second = parser.get_group("SecondGroup").unwrap();
+
+        assert!(parser.default_group.is_empty());
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+
+        if let (Drawables::Triangle(t1), Drawables::Triangle(t2)) = (&first[0], &second[0]) {
+            assert_eq!(t1.p3, parser.vertices[2]);
+            assert_eq!(t2.p3, parser.vertices[3]);
+        } else {
+            panic!("Expected two triangles");
+        }
+    }
+
+    #[test]
+    fn vertex_normal_records() {
+        let file = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3";
+
+        let parser = ObjParser::parse(file);
+        assert_eq!(parser.normals[0], Tuple::new_vector(0., 0., 1.));
+        assert_eq!(parser.normals[1], Tuple::new_vector(0.707, 0., -0.707));
+        assert_eq!(parser.normals[2], Tuple::new_vector(1., 2., 3.));
+    }
+
+    #[test]
+    fn texture_coordinate_records() {
+        let parser = ObjParser::parse("vt 0.5 0.25\nvt 1 0 0");
+        assert_eq!(parser.ignored, 0);
+        assert_eq!(parser.texture_coords, vec![(0.5, 0.25), (1., 0.)]);
+    }
+
+    #[test]
+    fn faces_with_normals() {
+        let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vt 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+f 1//3 2//1 3//2
+f 1/1/3 2/1/1 3/1/2";
+
+        let parser = ObjParser::parse(file);
+        assert_eq!(parser.ignored, 0);
+        assert_eq!(parser.default_group.len(), 2);
+
+        for object in parser.default_group.iter() {
+            if let Drawables::SmoothTriangle(t) = object {
+                assert_eq!(t.p1, parser.vertices[0]);
+                assert_eq!(t.p2, parser.vertices[1]);
+                assert_eq!(t.p3, parser.vertices[2]);
+                assert_eq!(t.n1, parser.normals[2]);
+                assert_eq!(t.n2, parser.normals[0]);
+                assert_eq!(t.n3, parser.normals[1]);
+            } else {
+                panic!("Expected a smooth triangle");
+            }
+        }
+    }
+
+    #[test]
+    fn negative_and_out_of_range_indices() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+f -3 -2 -1
+f 1 2 4";
+
+        let parser = ObjParser::parse(file);
+        assert_eq!(parser.default_group.len(), 1);
+        assert_eq!(parser.ignored, 1);
+    }
+
+    #[test]
+    fn objects_can_be_rendered_in_world() {
+        let file = "v -1 1 0
+v -1 -1 0
+v 1 -1 0
+v 1 1 0
+g Quad
+f 1 2 3 4";
+
+        let parser = ObjParser::parse(file);
+        let w = World {
+            objects: parser.to_objects(),
+            ..Default::default()
+        };
+
+        let r = Ray::new(Tuple::new_point(0.5, 0.5, -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        assert_eq!(w.objects.len(), 2);
+        assert_ne!(w.color_at(r, 1), Color::new(0., 0., 0.));
+    }
+
+    #[test]
+    fn convert_obj_to_group() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+
+        let parser = ObjParser::parse(file);
+        let g = parser.to_group();
+
+        assert_eq!(g.children().len(), 3);
+        assert_eq!(g.children()[0], parser.default_group[0]);
+        let mut first_group = Group::new();
+        for child in parser.get_group("FirstGroup").unwrap().iter() {
+            first_group.add_child(child.clone());
+        }
+        assert_eq!(g.children()[1], Drawables::Group(first_group));
+    }
+}
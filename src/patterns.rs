@@ -1,75 +1,200 @@
+use std::fmt;
+use std::sync::Arc;
+
 use super::color::Color;
 use super::tuple::Tuple;
 use super::generics::{Drawables, Drawable};
 use super::matrix::Matrix4;
+use super::texture::{cube_uv, face_from_point, CubeFace, UvMapping, UvPattern};
+use super::noise::{Fractal, Noise};
 
-// TODO: This maybe doesn't have to be an enum and just some structs
-// implementing a trait with a shared struct they hold.
-// But then it would mean the material has to hold a pointer to the pattern because
-// of possible size differences
+// The built in patterns are an enum so that materials can hold them without a
+// pointer. Anything else that implements Pattern can go in Patterns::Custom.
 
 pub trait Pattern {
     fn color_at(&self, point: Tuple) -> Color;
     fn set_transform(&mut self, transform: Matrix4);
     fn get_transform(&self) -> Matrix4;
+    fn get_inverse_transform(&self) -> Option<Matrix4>;
     fn get_a(&self) -> Color;
     fn get_b(&self) -> Color;
+
+    // For patterns inside of other patterns, the point is in the space of
+    // the outer one. A transform that can't be inverted squashes the pattern
+    // down to nothing, so there is nothing to see.
+    fn color_at_nested(&self, point: Tuple) -> Color {
+        match self.get_inverse_transform() {
+            Some(inverse) => self.color_at(inverse * point),
+            None => Color::new(0., 0., 0.),
+        }
+    }
 }
 
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Patterns {
     Stripe(Stripe),
     Gradient(Gradient),
     Ring(Ring),
     Checker(Checker),
     Test(Test),
+    TextureMap(TextureMap),
+    // Boxed, six faces would make every other pattern much bigger
+    CubeMap(Box<CubeMap>),
+    Perturbed(Box<Perturbed>),
+    Blended(Box<Blended>),
+    Custom(Custom),
+}
+
+// What goes in the a and b slots of the simple patterns, either a flat color
+// or a whole other pattern, so that there can be checkers of stripes
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    Color(Color),
+    Pattern(Box<Patterns>),
+}
+
+#[allow(dead_code)]
+impl Fill {
+    pub fn color_at(&self, point: Tuple) -> Color {
+        match self {
+            Fill::Color(c) => *c,
+            Fill::Pattern(p) => p.color_at_nested(point),
+        }
+    }
+
+    // The flat color, or the first color of the pattern
+    pub fn color(&self) -> Color {
+        match self {
+            Fill::Color(c) => *c,
+            Fill::Pattern(p) => p.get_a(),
+        }
+    }
+}
+
+impl From<Color> for Fill {
+    fn from(color: Color) -> Self {
+        Fill::Color(color)
+    }
+}
+
+impl From<Patterns> for Fill {
+    fn from(pattern: Patterns) -> Self {
+        Fill::Pattern(Box::new(pattern))
+    }
 }
 
 
 #[allow(dead_code)]
 impl Patterns {
-    pub fn new_stripe(a: Color, b: Color) -> Self {
+    pub fn new_stripe(a: impl Into<Fill>, b: impl Into<Fill>) -> Self {
         Self::Stripe(Stripe{
-            a,
-            b,
-            transform: Matrix4::new_identity()
+            a: a.into(),
+            b: b.into(),
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
         })
     }
 
-    pub fn new_gradient(a: Color, b: Color) -> Self {
+    pub fn new_gradient(a: impl Into<Fill>, b: impl Into<Fill>) -> Self {
         Self::Gradient(Gradient{
-            a,
-            b,
-            transform: Matrix4::new_identity()
+            a: a.into(),
+            b: b.into(),
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
         })
     }
 
-    pub fn new_ring(a: Color, b: Color) -> Self {
+    pub fn new_ring(a: impl Into<Fill>, b: impl Into<Fill>) -> Self {
         Self::Ring(Ring{
-            a,
-            b,
-            transform: Matrix4::new_identity()
+            a: a.into(),
+            b: b.into(),
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
         })
     }
 
-    pub fn new_checker(a: Color, b: Color) -> Self {
+    pub fn new_checker(a: impl Into<Fill>, b: impl Into<Fill>) -> Self {
         Self::Checker(Checker{
+            a: a.into(),
+            b: b.into(),
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
+        })
+    }
+
+    pub fn new_texture_map(uv_pattern: UvPattern, mapping: UvMapping) -> Self {
+        Self::TextureMap(TextureMap{
+            uv_pattern,
+            mapping,
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
+        })
+    }
+
+    pub fn new_cube_map(
+        left: UvPattern,
+        right: UvPattern,
+        front: UvPattern,
+        back: UvPattern,
+        up: UvPattern,
+        down: UvPattern,
+    ) -> Self {
+        Self::CubeMap(Box::new(CubeMap{
+            left,
+            right,
+            front,
+            back,
+            up,
+            down,
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
+        }))
+    }
+
+    // Jitters the points another pattern sees, by up to scale in each direction
+    pub fn new_perturbed(pattern: Patterns, fractal: Fractal, octaves: usize, scale: f64, seed: u64) -> Self {
+        Self::Perturbed(Box::new(Perturbed{
+            pattern,
+            noise: Noise::new(seed),
+            fractal,
+            octaves,
+            scale,
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
+        }))
+    }
+
+    // Mixes two patterns, weight 0 is all a and 1 is all b
+    pub fn new_blended(a: Patterns, b: Patterns, weight: f64) -> Self {
+        Self::Blended(Box::new(Blended{
             a,
             b,
-            transform: Matrix4::new_identity()
+            weight,
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
+        }))
+    }
+
+    // Shared, so the same pattern can go on many materials
+    pub fn new_custom(pattern: Arc<dyn Pattern + Send + Sync>) -> Self {
+        Self::Custom(Custom{
+            pattern,
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
         })
     }
 
     pub fn new_test() -> Self {
         Self::Test(Test{
-            transform: Matrix4::new_identity()
+            transform: Matrix4::new_identity(),
+            transform_inverse: Some(Matrix4::new_identity()),
         })
     }
 
     pub fn color_at_object(&self, object: &Drawables, point: Tuple) -> Option<Color> {
-        let object_point = object.get_transform().inverse()? * point;
-        let pattern_point = self.get_transform().inverse()? * object_point;
+        let object_point = object.get_shape().world_to_object(point)?;
+        let pattern_point = self.get_inverse_transform()? * object_point;
 
         Some(self.color_at(pattern_point))
     }
@@ -82,7 +207,12 @@ impl Pattern for Patterns {
             Self::Gradient(g) => g.color_at(point),
             Self::Ring(r) => r.color_at(point),
             Self::Checker(c) => c.color_at(point),
-            Self::Test(t) => t.color_at(point)
+            Self::Test(t) => t.color_at(point),
+            Self::TextureMap(t) => t.color_at(point),
+            Self::CubeMap(c) => c.color_at(point),
+            Self::Perturbed(p) => p.color_at(point),
+            Self::Blended(b) => b.color_at(point),
+            Self::Custom(c) => c.color_at(point),
         }
     }
 
@@ -92,7 +222,12 @@ impl Pattern for Patterns {
             Self::Gradient(g) => g.set_transform(transform),
             Self::Ring(r) => r.set_transform(transform),
             Self::Checker(c) => c.set_transform(transform),
-            Self::Test(t) => t.set_transform(transform)
+            Self::Test(t) => t.set_transform(transform),
+            Self::TextureMap(t) => t.set_transform(transform),
+            Self::CubeMap(c) => c.set_transform(transform),
+            Self::Perturbed(p) => p.set_transform(transform),
+            Self::Blended(b) => b.set_transform(transform),
+            Self::Custom(c) => c.set_transform(transform),
         }
     }
 
@@ -102,7 +237,27 @@ impl Pattern for Patterns {
             Self::Gradient(g) => g.get_transform(),
             Self::Ring(r) => r.get_transform(),
             Self::Checker(c) => c.get_transform(),
-            Self::Test(t) => t.get_transform()
+            Self::Test(t) => t.get_transform(),
+            Self::TextureMap(t) => t.get_transform(),
+            Self::CubeMap(c) => c.get_transform(),
+            Self::Perturbed(p) => p.get_transform(),
+            Self::Blended(b) => b.get_transform(),
+            Self::Custom(c) => c.get_transform(),
+        }
+    }
+
+    fn get_inverse_transform(&self) -> Option<Matrix4> {
+        match self {
+            Self::Stripe(s) => s.get_inverse_transform(),
+            Self::Gradient(g) => g.get_inverse_transform(),
+            Self::Ring(r) => r.get_inverse_transform(),
+            Self::Checker(c) => c.get_inverse_transform(),
+            Self::Test(t) => t.get_inverse_transform(),
+            Self::TextureMap(t) => t.get_inverse_transform(),
+            Self::CubeMap(c) => c.get_inverse_transform(),
+            Self::Perturbed(p) => p.get_inverse_transform(),
+            Self::Blended(b) => b.get_inverse_transform(),
+            Self::Custom(c) => c.get_inverse_transform(),
         }
     }
 
@@ -112,7 +267,12 @@ impl Pattern for Patterns {
             Self::Gradient(g) => g.get_a(),
             Self::Ring(r) => r.get_a(),
             Self::Checker(c) => c.get_a(),
-            Self::Test(t) => t.get_a()
+            Self::Test(t) => t.get_a(),
+            Self::TextureMap(t) => t.get_a(),
+            Self::CubeMap(c) => c.get_a(),
+            Self::Perturbed(p) => p.get_a(),
+            Self::Blended(b) => b.get_a(),
+            Self::Custom(c) => c.get_a(),
         }
     }
 
@@ -122,101 +282,128 @@ impl Pattern for Patterns {
             Self::Gradient(g) => g.get_b(),
             Self::Ring(r) => r.get_b(),
             Self::Checker(c) => c.get_b(),
-            Self::Test(t) => t.get_b()
+            Self::Test(t) => t.get_b(),
+            Self::TextureMap(t) => t.get_b(),
+            Self::CubeMap(c) => c.get_b(),
+            Self::Perturbed(p) => p.get_b(),
+            Self::Blended(b) => b.get_b(),
+            Self::Custom(c) => c.get_b(),
         }
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Stripe {
-    pub a: Color,
-    pub b: Color,
-    pub transform: Matrix4,
+    pub a: Fill,
+    pub b: Fill,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
 
 }
 
 impl Pattern for Stripe {
     fn color_at(&self, point: Tuple) -> Color {
         if point.x.floor() % 2.0 == 0.0 {
-            return self.a;
+            return self.a.color_at(point);
         }
-        self.b
+        self.b.color_at(point)
     }
 
 
-    fn set_transform(&mut self, transform: Matrix4) { self.transform = transform }
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
     fn get_transform(&self) -> Matrix4 { self.transform }
-    fn get_a(&self) -> Color { self.a }
-    fn get_b(&self) -> Color { self.b }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.a.color() }
+    fn get_b(&self) -> Color { self.b.color() }
 }
 
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gradient {
-    pub a: Color,
-    pub b: Color,
-    pub transform: Matrix4,
+    pub a: Fill,
+    pub b: Fill,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
 }
 
 impl Pattern for Gradient {
     fn color_at(&self, point: Tuple) -> Color {
-        let distance = self.b - self.a;
+        let a = self.a.color_at(point);
+        let distance = self.b.color_at(point) - a;
         let fraction = point.x - point.x.floor();
 
-        self.a + (distance * fraction)
+        a + (distance * fraction)
     }
 
-    fn set_transform(&mut self, transform: Matrix4) { self.transform = transform }
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
     fn get_transform(&self) -> Matrix4 { self.transform }
-    fn get_a(&self) -> Color { self.a }
-    fn get_b(&self) -> Color { self.b }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.a.color() }
+    fn get_b(&self) -> Color { self.b.color() }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Ring {
-    pub a: Color,
-    pub b: Color,
-    pub transform: Matrix4,
+    pub a: Fill,
+    pub b: Fill,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
 }
 
 impl Pattern for Ring {
     fn color_at(&self, point: Tuple) -> Color {
         if (point.x.powf(2.) + point.z.powf(2.)).sqrt() % 2. == 0. {
-           return self.a;
+           return self.a.color_at(point);
         }
-        self.b
+        self.b.color_at(point)
     }
 
-    fn set_transform(&mut self, transform: Matrix4) { self.transform = transform }
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
     fn get_transform(&self) -> Matrix4 { self.transform }
-    fn get_a(&self) -> Color { self.a }
-    fn get_b(&self) -> Color { self.b }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.a.color() }
+    fn get_b(&self) -> Color { self.b.color() }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Checker {
-    pub a: Color,
-    pub b: Color,
-    pub transform: Matrix4,
+    pub a: Fill,
+    pub b: Fill,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
 }
 
 impl Pattern for Checker {
     fn color_at(&self, point: Tuple) -> Color {
         if (point.x.floor() + point.y.floor() + point.z.floor()) % 2. == 0. {
-           return self.a;
+           return self.a.color_at(point);
         }
-        self.b
+        self.b.color_at(point)
     }
 
-    fn set_transform(&mut self, transform: Matrix4) { self.transform = transform }
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
     fn get_transform(&self) -> Matrix4 { self.transform }
-    fn get_a(&self) -> Color { self.a }
-    fn get_b(&self) -> Color { self.b }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.a.color() }
+    fn get_b(&self) -> Color { self.b.color() }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Test {
-    pub transform: Matrix4,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
 }
 
 impl Pattern for Test {
@@ -224,8 +411,180 @@ impl Pattern for Test {
         Color::new(point.x, point.y, point.z)
     }
 
-    fn set_transform(&mut self, transform: Matrix4) { self.transform = transform }
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
     fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
     fn get_a(&self) -> Color { Color::new(0., 0., 0.) }
     fn get_b(&self) -> Color { Color::new(1., 1., 1.) }
 }
+
+// Flattens the surface with the mapping, and looks the color up in a 2D pattern
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureMap {
+    pub uv_pattern: UvPattern,
+    pub mapping: UvMapping,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
+}
+
+impl Pattern for TextureMap {
+    fn color_at(&self, point: Tuple) -> Color {
+        let (u, v) = self.mapping.map(point);
+        self.uv_pattern.color_at(u, v)
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
+    fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { Color::new(0., 0., 0.) }
+    fn get_b(&self) -> Color { Color::new(1., 1., 1.) }
+}
+
+// Each face of a unit cube gets its own 2D pattern, for skyboxes and dice
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubeMap {
+    pub left: UvPattern,
+    pub right: UvPattern,
+    pub front: UvPattern,
+    pub back: UvPattern,
+    pub up: UvPattern,
+    pub down: UvPattern,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
+}
+
+impl CubeMap {
+    pub fn face(&self, face: CubeFace) -> &UvPattern {
+        match face {
+            CubeFace::Left => &self.left,
+            CubeFace::Right => &self.right,
+            CubeFace::Front => &self.front,
+            CubeFace::Back => &self.back,
+            CubeFace::Up => &self.up,
+            CubeFace::Down => &self.down,
+        }
+    }
+}
+
+impl Pattern for CubeMap {
+    fn color_at(&self, point: Tuple) -> Color {
+        let face = face_from_point(point);
+        let (u, v) = cube_uv(face, point);
+        self.face(face).color_at(u, v)
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
+    fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { Color::new(0., 0., 0.) }
+    fn get_b(&self) -> Color { Color::new(1., 1., 1.) }
+}
+
+// Moves the point around with noise before handing it to the inner pattern,
+// which turns stripes into marble and rings into wood grain
+#[derive(Debug, Clone, PartialEq)]
+pub struct Perturbed {
+    pub pattern: Patterns,
+    pub noise: Noise,
+    pub fractal: Fractal,
+    pub octaves: usize,
+    pub scale: f64,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
+}
+
+impl Pattern for Perturbed {
+    fn color_at(&self, point: Tuple) -> Color {
+        // Sampled at points far apart, so x, y and z move independently
+        let offset = Tuple::new_vector(
+            self.noise.fractal(self.fractal, point, self.octaves),
+            self.noise.fractal(self.fractal, point + Tuple::new_vector(31.4, 15.9, 26.5), self.octaves),
+            self.noise.fractal(self.fractal, point + Tuple::new_vector(-35.8, 97.9, -32.3), self.octaves),
+        );
+
+        self.pattern.color_at_nested(point + offset * self.scale)
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
+    fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.pattern.get_a() }
+    fn get_b(&self) -> Color { self.pattern.get_b() }
+}
+
+// Both patterns keep their own transform, inside of the blend's one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blended {
+    pub a: Patterns,
+    pub b: Patterns,
+    pub weight: f64,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
+}
+
+impl Pattern for Blended {
+    fn color_at(&self, point: Tuple) -> Color {
+        self.a.color_at_nested(point) * (1. - self.weight) + self.b.color_at_nested(point) * self.weight
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
+    fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.a.get_a() }
+    fn get_b(&self) -> Color { self.b.get_b() }
+}
+
+// Any other pattern. It keeps its own transform too, which applies inside of
+// this one, since the shared pattern can't be changed.
+#[derive(Clone)]
+pub struct Custom {
+    pub pattern: Arc<dyn Pattern + Send + Sync>,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
+}
+
+// Can't look inside of the pattern, so it is the same only if it is the
+// very same pattern
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.pattern, &other.pattern) && self.transform == other.transform
+    }
+}
+
+impl fmt::Debug for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Custom")
+            .field("transform", &self.transform)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Pattern for Custom {
+    fn color_at(&self, point: Tuple) -> Color {
+        self.pattern.color_at_nested(point)
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+    }
+    fn get_transform(&self) -> Matrix4 { self.transform }
+    fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform_inverse }
+    fn get_a(&self) -> Color { self.pattern.get_a() }
+    fn get_b(&self) -> Color { self.pattern.get_b() }
+}
@@ -1,15 +1,20 @@
 #[cfg(test)]
 
 mod patterns_tests {
-    use crate::patterns::{Patterns, Pattern};
+    use std::f64::consts::PI;
+    use std::sync::Arc;
+    use crate::patterns::{Fill, Patterns, Pattern};
     use crate::color::Color;
     use crate::tuple::Tuple;
     use crate::material::Material;
     use crate::point_light::PointLight;
+    use crate::lights::Lights;
     use crate::utils::lighting;
     use crate::sphere::Sphere;
     use crate::matrix::Matrix4;
     use crate::generics::Drawables;
+    use crate::texture::{UvMapping, UvPattern};
+    use crate::noise::Fractal;
 
     const BLACK: Color = Color {
         r: 0.,
@@ -23,7 +28,22 @@ mod patterns_tests {
         b: 1.
     };
 
-    // TODO: Check that default pattern has identity matrix as transform
+    #[test]
+    fn default_pattern_transformation() {
+        let pattern = Patterns::new_test();
+        assert_eq!(pattern.get_transform(), Matrix4::new_identity());
+        assert_eq!(pattern.get_inverse_transform(), Some(Matrix4::new_identity()));
+    }
+
+    #[test]
+    fn setting_pattern_transform_updates_inverse() {
+        let mut pattern = Patterns::new_test();
+        let t = Matrix4::new_translation(1., 2., 3.);
+        pattern.set_transform(t);
+
+        assert_eq!(pattern.get_transform(), t);
+        assert_eq!(pattern.get_inverse_transform(), t.inverse());
+    }
 
     #[test]
     fn create_stripe_pattern() {
@@ -75,10 +95,10 @@ mod patterns_tests {
 
         let eye_v = Tuple::new_vector(0., 0., -1.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
-        let light = PointLight::new(Tuple::new_point(0., 0., -10.), WHITE).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), WHITE).unwrap());
 
-        let c1 = lighting(&object, Tuple::new_point(0.9, 0., 0.), light, eye_v, normal_v, false).unwrap();
-        let c2 = lighting(&object, Tuple::new_point(1.1, 0., 0.), light, eye_v, normal_v, false).unwrap();
+        let c1 = lighting(&object, Tuple::new_point(0.9, 0., 0.), light, eye_v, normal_v, 1.).unwrap();
+        let c2 = lighting(&object, Tuple::new_point(1.1, 0., 0.), light, eye_v, normal_v, 1.).unwrap();
 
         assert_eq!(c1, WHITE);
         assert_eq!(c2, BLACK);
@@ -154,4 +174,280 @@ mod patterns_tests {
         assert_eq!(pattern.color_at(Tuple::new_point(0., 0., 0.99)), WHITE);
         assert_eq!(pattern.color_at(Tuple::new_point(0., 0., 1.01)), BLACK);
     }
+
+    #[test]
+    fn texture_map_with_spherical_map() {
+        let checkers = UvPattern::new_checkers(16., 8., BLACK, WHITE);
+        let pattern = Patterns::new_texture_map(checkers, UvMapping::Spherical);
+        let cases = [
+            (Tuple::new_point(0.4315, 0.4670, 0.7719), WHITE),
+            (Tuple::new_point(-0.9654, 0.2552, -0.0534), BLACK),
+            (Tuple::new_point(0.1039, 0.7090, 0.6975), WHITE),
+            (Tuple::new_point(-0.4986, -0.7856, -0.3663), BLACK),
+            (Tuple::new_point(-0.0317, -0.9395, 0.3411), BLACK),
+            (Tuple::new_point(0.4809, -0.7721, 0.4154), BLACK),
+            (Tuple::new_point(0.0285, -0.9612, -0.2745), BLACK),
+            (Tuple::new_point(-0.5734, -0.2162, -0.7903), WHITE),
+            (Tuple::new_point(0.7688, -0.1470, 0.6223), BLACK),
+            (Tuple::new_point(-0.7652, 0.2175, 0.6060), BLACK),
+        ];
+
+        for (point, color) in cases.iter() {
+            assert_eq!(pattern.color_at(*point), *color);
+        }
+    }
+
+    #[test]
+    fn texture_map_on_object() {
+        let checkers = UvPattern::new_checkers(2., 2., BLACK, WHITE);
+        let mut pattern = Patterns::new_texture_map(checkers, UvMapping::Planar);
+        pattern.set_transform(Matrix4::new_scaling(2., 2., 2.));
+        let object = Drawables::Sphere(Sphere::new());
+
+        assert_eq!(pattern.color_at_object(&object, Tuple::new_point(0.5, 0., 0.5)), Some(BLACK));
+        assert_eq!(pattern.color_at_object(&object, Tuple::new_point(1.5, 0., 0.5)), Some(WHITE));
+    }
+
+    #[test]
+    fn cube_map_picks_face_and_orientation() {
+        let red = Color::new(1., 0., 0.);
+        let yellow = Color::new(1., 1., 0.);
+        let brown = Color::new(1., 0.5, 0.);
+        let green = Color::new(0., 1., 0.);
+        let cyan = Color::new(0., 1., 1.);
+        let blue = Color::new(0., 0., 1.);
+        let purple = Color::new(1., 0., 1.);
+        let white = Color::new(1., 1., 1.);
+
+        let pattern = Patterns::new_cube_map(
+            UvPattern::new_align_check(yellow, cyan, red, blue, brown),
+            UvPattern::new_align_check(red, yellow, purple, green, white),
+            UvPattern::new_align_check(cyan, red, yellow, brown, green),
+            UvPattern::new_align_check(green, purple, cyan, white, blue),
+            UvPattern::new_align_check(brown, cyan, purple, red, yellow),
+            UvPattern::new_align_check(purple, brown, green, blue, white),
+        );
+        let cases = [
+            // Left
+            (Tuple::new_point(-1., 0., 0.), yellow),
+            (Tuple::new_point(-1., 0.9, -0.9), cyan),
+            (Tuple::new_point(-1., 0.9, 0.9), red),
+            (Tuple::new_point(-1., -0.9, -0.9), blue),
+            (Tuple::new_point(-1., -0.9, 0.9), brown),
+            // Front
+            (Tuple::new_point(0., 0., 1.), cyan),
+            (Tuple::new_point(-0.9, 0.9, 1.), red),
+            (Tuple::new_point(0.9, 0.9, 1.), yellow),
+            (Tuple::new_point(-0.9, -0.9, 1.), brown),
+            (Tuple::new_point(0.9, -0.9, 1.), green),
+            // Right
+            (Tuple::new_point(1., 0., 0.), red),
+            (Tuple::new_point(1., 0.9, 0.9), yellow),
+            (Tuple::new_point(1., 0.9, -0.9), purple),
+            (Tuple::new_point(1., -0.9, 0.9), green),
+            (Tuple::new_point(1., -0.9, -0.9), white),
+            // Back
+            (Tuple::new_point(0., 0., -1.), green),
+            (Tuple::new_point(0.9, 0.9, -1.), purple),
+            (Tuple::new_point(-0.9, 0.9, -1.), cyan),
+            (Tuple::new_point(0.9, -0.9, -1.), white),
+            (Tuple::new_point(-0.9, -0.9, -1.), blue),
+            // Up
+            (Tuple::new_point(0., 1., 0.), brown),
+            (Tuple::new_point(-0.9, 1., -0.9), cyan),
+            (Tuple::new_point(0.9, 1., -0.9), purple),
+            (Tuple::new_point(-0.9, 1., 0.9), red),
+            (Tuple::new_point(0.9, 1., 0.9), yellow),
+            // Down
+            (Tuple::new_point(0., -1., 0.), purple),
+            (Tuple::new_point(-0.9, -1., 0.9), brown),
+            (Tuple::new_point(0.9, -1., 0.9), green),
+            (Tuple::new_point(-0.9, -1., -0.9), blue),
+            (Tuple::new_point(0.9, -1., -0.9), white),
+        ];
+
+        for (point, color) in cases.iter() {
+            assert_eq!(pattern.color_at(*point), *color, "at {:?}", point);
+        }
+    }
+
+    #[test]
+    fn perturbed_without_scale_is_the_inner_pattern() {
+        let stripe = Patterns::new_stripe(WHITE, BLACK);
+        let pattern = Patterns::new_perturbed(stripe.clone(), Fractal::Fbm, 3, 0., 1);
+
+        for x in 0..20 {
+            let point = Tuple::new_point(x as f64 * 0.37, 0.5, -0.2);
+            assert_eq!(pattern.color_at(point), stripe.color_at(point));
+        }
+    }
+
+    #[test]
+    fn perturbed_moves_the_stripes() {
+        let stripe = Patterns::new_stripe(WHITE, BLACK);
+        let pattern = Patterns::new_perturbed(stripe.clone(), Fractal::Turbulence, 3, 1., 1);
+
+        let points: Vec<Tuple> = (0..100)
+            .map(|i| Tuple::new_point(i as f64 * 0.13, i as f64 * 0.29, i as f64 * -0.07))
+            .collect();
+        assert!(points.iter().any(|p| pattern.color_at(*p) != stripe.color_at(*p)));
+
+        // Always the same for the same point and seed
+        let again = Patterns::new_perturbed(stripe, Fractal::Turbulence, 3, 1., 1);
+        assert!(points.iter().all(|p| pattern.color_at(*p) == again.color_at(*p)));
+    }
+
+    #[test]
+    fn perturbed_uses_the_inner_transform() {
+        let mut inner = Patterns::new_test();
+        inner.set_transform(Matrix4::new_scaling(2., 2., 2.));
+        let pattern = Patterns::new_perturbed(inner, Fractal::Fbm, 1, 0., 1);
+
+        assert_eq!(pattern.color_at(Tuple::new_point(2., 4., 6.)), Color::new(1., 2., 3.));
+    }
+
+    #[test]
+    fn checker_of_stripes() {
+        let red = Color::new(1., 0., 0.);
+        let mut stripe = Patterns::new_stripe(WHITE, red);
+        stripe.set_transform(Matrix4::new_scaling(0.5, 0.5, 0.5));
+        let pattern = Patterns::new_checker(stripe, BLACK);
+
+        assert_eq!(pattern.color_at(Tuple::new_point(0.25, 0., 0.)), WHITE);
+        assert_eq!(pattern.color_at(Tuple::new_point(0.75, 0., 0.)), red);
+        assert_eq!(pattern.color_at(Tuple::new_point(1.25, 0., 0.)), BLACK);
+        assert_eq!(pattern.color_at(Tuple::new_point(0.25, 0., 1.25)), BLACK);
+        assert_eq!(pattern.get_a(), WHITE);
+    }
+
+    #[test]
+    fn fills_from_colors_and_patterns() {
+        assert_eq!(Fill::from(WHITE), Fill::Color(WHITE));
+        assert_eq!(Fill::from(Patterns::new_test()), Fill::Pattern(Box::new(Patterns::new_test())));
+
+        let point = Tuple::new_point(0.1, 0.2, 0.3);
+        assert_eq!(Fill::from(WHITE).color_at(point), WHITE);
+        assert_eq!(Fill::from(Patterns::new_test()).color_at(point), Color::new(0.1, 0.2, 0.3));
+    }
+
+    #[test]
+    fn gradient_between_patterns() {
+        let pattern = Patterns::new_gradient(Patterns::new_test(), WHITE);
+
+        assert_eq!(pattern.color_at(Tuple::new_point(0., 0.5, 0.)), Color::new(0., 0.5, 0.));
+        assert_eq!(pattern.color_at(Tuple::new_point(0.5, 0., 0.)), Color::new(0.75, 0.5, 0.5));
+    }
+
+    #[test]
+    fn blended_averages_two_patterns() {
+        let a = Patterns::new_stripe(WHITE, BLACK);
+        let mut b = Patterns::new_stripe(WHITE, BLACK);
+        b.set_transform(Matrix4::new_rotation_y(PI / 2.));
+        let pattern = Patterns::new_blended(a, b, 0.5);
+
+        let grey = Color::new(0.5, 0.5, 0.5);
+        // b is turned so that its stripes change along z instead
+        assert_eq!(pattern.color_at(Tuple::new_point(0.5, 0., -0.5)), WHITE);
+        assert_eq!(pattern.color_at(Tuple::new_point(1.5, 0., -0.5)), grey);
+        assert_eq!(pattern.color_at(Tuple::new_point(0.5, 0., 0.5)), grey);
+        assert_eq!(pattern.color_at(Tuple::new_point(1.5, 0., 0.5)), BLACK);
+    }
+
+    #[test]
+    fn blended_by_weight() {
+        let red = Color::new(1., 0., 0.);
+        let green = Color::new(0., 1., 0.);
+        let blue = Color::new(0., 0., 1.);
+        let pattern = Patterns::new_blended(
+            Patterns::new_stripe(red, green),
+            Patterns::new_stripe(blue, green),
+            0.25,
+        );
+
+        assert_eq!(pattern.color_at(Tuple::new_point(0., 0., 0.)), Color::new(0.75, 0., 0.25));
+        assert_eq!(pattern.color_at(Tuple::new_point(1., 0., 0.)), green);
+        // The first color of the first pattern and the second of the second
+        assert_eq!(pattern.get_a(), red);
+        assert_eq!(pattern.get_b(), green);
+    }
+
+    // A pattern from outside of patterns.rs, a on the bottom half and b on top
+    struct Halves {
+        transform: Matrix4,
+    }
+
+    impl Pattern for Halves {
+        fn color_at(&self, point: Tuple) -> Color {
+            if point.y < 0. { self.get_a() } else { self.get_b() }
+        }
+
+        fn set_transform(&mut self, transform: Matrix4) { self.transform = transform; }
+        fn get_transform(&self) -> Matrix4 { self.transform }
+        fn get_inverse_transform(&self) -> Option<Matrix4> { self.transform.inverse() }
+        fn get_a(&self) -> Color { BLACK }
+        fn get_b(&self) -> Color { WHITE }
+    }
+
+    #[test]
+    fn custom_pattern_on_object() {
+        let halves = Halves { transform: Matrix4::new_translation(0., 1., 0.) };
+        let mut pattern = Patterns::new_custom(Arc::new(halves));
+        pattern.set_transform(Matrix4::new_translation(0., 1., 0.));
+        let object = Drawables::Sphere(Sphere::new());
+
+        // Both transforms move the middle up, so it ends up at y = 2
+        assert_eq!(pattern.color_at_object(&object, Tuple::new_point(0., 1.9, 0.)), Some(BLACK));
+        assert_eq!(pattern.color_at_object(&object, Tuple::new_point(0., 2.1, 0.)), Some(WHITE));
+        assert_eq!((pattern.get_a(), pattern.get_b()), (BLACK, WHITE));
+    }
+
+    #[test]
+    fn lighting_with_custom_pattern() {
+        let material = Material{
+            pattern: Some(Patterns::new_custom(Arc::new(Halves { transform: Matrix4::new_identity() }))),
+            ambient: 1.0,
+            diffuse: 0.0,
+            specular: 0.0,
+            ..Default::default()
+        };
+
+        let mut sphere = Sphere::new();
+        sphere.shape.material = material;
+        let object = Drawables::Sphere(sphere);
+
+        let eye_v = Tuple::new_vector(0., 0., -1.);
+        let normal_v = Tuple::new_vector(0., 0., -1.);
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), WHITE).unwrap());
+
+        let c1 = lighting(&object, Tuple::new_point(0., -0.5, 0.), light, eye_v, normal_v, 1.).unwrap();
+        let c2 = lighting(&object, Tuple::new_point(0., 0.5, 0.), light, eye_v, normal_v, 1.).unwrap();
+
+        assert_eq!(c1, BLACK);
+        assert_eq!(c2, WHITE);
+    }
+
+    #[test]
+    fn custom_patterns_are_equal_when_shared() {
+        let shared: Arc<dyn Pattern + Send + Sync> = Arc::new(Halves { transform: Matrix4::new_identity() });
+        let a = Patterns::new_custom(shared.clone());
+        let b = Patterns::new_custom(shared);
+        let other = Patterns::new_custom(Arc::new(Halves { transform: Matrix4::new_identity() }));
+
+        assert_eq!(a, b);
+        assert_ne!(a, other);
+
+        let mut moved = b.clone();
+        moved.set_transform(Matrix4::new_scaling(2., 2., 2.));
+        assert_ne!(a, moved);
+    }
+
+    #[test]
+    fn custom_pattern_in_a_slot() {
+        let halves = Patterns::new_custom(Arc::new(Halves { transform: Matrix4::new_identity() }));
+        let pattern = Patterns::new_stripe(halves, Color::new(1., 0., 0.));
+
+        assert_eq!(pattern.color_at(Tuple::new_point(0.5, -0.5, 0.)), BLACK);
+        assert_eq!(pattern.color_at(Tuple::new_point(0.5, 0.5, 0.)), WHITE);
+        assert_eq!(pattern.color_at(Tuple::new_point(1.5, 0.5, 0.)), Color::new(1., 0., 0.));
+    }
 }
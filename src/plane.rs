@@ -4,8 +4,10 @@ use super::ray::Ray;
 use super::generics::Drawable;
 use super::tuple::Tuple;
 use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Plane {
     pub shape: Shape
 }
@@ -28,23 +30,40 @@ impl Plane {
 }
 
 impl Drawable for Plane {
-    fn local_intersect(&self, local_ray: Ray) -> Option<[Option<f64>; 2]> {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
         if local_ray.direction.y.abs() < EPSILON {
             return None;
         }
         let t = -local_ray.origin.y / local_ray.direction.y;
-        Some([Some(t), None])
+        Some(vec![t])
     }
 
-    fn local_normal_at(&self, _: Tuple) -> Tuple {
+    fn local_normal_at(&self, _: Tuple, _: &Intersection) -> Tuple {
         Tuple::new_vector(0., 1., 0.)
     }
 
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Tuple::new_point(f64::NEG_INFINITY, 0., f64::NEG_INFINITY),
+            Tuple::new_point(f64::INFINITY, 0., f64::INFINITY)
+        )
+    }
+
     fn get_transform(&self) -> Matrix4 {
-        self.shape.transform
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
     }
 
-    fn get_shape(&self) -> Shape {
-        self.shape
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Self::new()
     }
 }
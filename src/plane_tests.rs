@@ -3,17 +3,20 @@
 mod plane_tests {
     use crate::plane::*;
     use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
     use crate::ray::Ray;
     use crate::generics::{Drawable, Drawables};
 
     #[test]
     fn normal_at_plane() {
         let p = Plane::new();
+        let d = Drawables::Plane(p.clone());
+        let i = Intersection::new(0., &d);
         let expected = Tuple::new_vector(0., 1., 0.);
 
-        assert_eq!(p.local_normal_at(Tuple::new_point(0., 0., 0.)), expected);
-        assert_eq!(p.local_normal_at(Tuple::new_point(10., 0., -10.)), expected);
-        assert_eq!(p.local_normal_at(Tuple::new_point(-5., 0., 150.)), expected);
+        assert_eq!(p.local_normal_at(Tuple::new_point(0., 0., 0.), &i), expected);
+        assert_eq!(p.local_normal_at(Tuple::new_point(10., 0., -10.), &i), expected);
+        assert_eq!(p.local_normal_at(Tuple::new_point(-5., 0., 150.), &i), expected);
     }
 
     #[test]
@@ -40,10 +43,10 @@ mod plane_tests {
         let r = Ray::new(Tuple::new_point(0., 1., 0.), Tuple::new_vector(0., -1., 0.)).unwrap();
         let xs = p.intersect(r).unwrap();
 
-        assert_eq!(xs[0].unwrap().t, 1.);
-        assert_eq!(*xs[0].unwrap().object, p);
+        assert_eq!(xs[0].t, 1.);
+        assert_eq!(*xs[0].object, p);
 
-        assert_eq!(xs[1], None);
+        assert_eq!(xs.len(), 1);
     }
 
     #[test]
@@ -52,9 +55,9 @@ mod plane_tests {
         let r = Ray::new(Tuple::new_point(0., -1., 0.), Tuple::new_vector(0., 1., 0.)).unwrap();
         let xs = p.intersect(r).unwrap();
 
-        assert_eq!(xs[0].unwrap().t, 1.);
-        assert_eq!(*xs[0].unwrap().object, p);
+        assert_eq!(xs[0].t, 1.);
+        assert_eq!(*xs[0].object, p);
 
-        assert_eq!(xs[1], None);
+        assert_eq!(xs.len(), 1);
     }
 }
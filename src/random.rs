@@ -0,0 +1,35 @@
+// A small xorshift64* generator, so that we don't need a dependency for
+// a handful of random numbers. Renders seed one per pixel, which keeps
+// them the same no matter how the pixels are split between threads.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rng {
+    state: u64,
+}
+
+#[allow(dead_code)]
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Spread the seed out with splitmix64, since nearby pixels get nearby
+        // seeds and xorshift needs a state that isn't zero
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        Self {
+            state: if z == 0 { 0x9e37_79b9_7f4a_7c15 } else { z },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // Between 0 inclusive and 1 exclusive
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
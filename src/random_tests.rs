@@ -0,0 +1,35 @@
+#[cfg(test)]
+
+mod random_tests {
+    use crate::random::Rng;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn nearby_seeds_differ() {
+        assert_ne!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn floats_are_in_unit_range() {
+        let mut rng = Rng::new(7);
+        let mut sum = 0.;
+
+        for _ in 0..1000 {
+            let n = rng.next_f64();
+            assert!((0. ..1.).contains(&n));
+            sum += n;
+        }
+
+        // Roughly uniform
+        assert!((sum / 1000. - 0.5).abs() < 0.05);
+    }
+}
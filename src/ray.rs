@@ -23,27 +23,22 @@ impl Ray {
         self.origin + self.direction * t
     }
 
-    pub fn intersect(self, object: &Drawables) -> Option<[Option<Intersection>; 2]> {
-        let local_ray = self.transform(object.get_transform().inverse()?);
+    pub fn intersect(self, object: &Drawables) -> Option<Vec<Intersection>> {
+        let local_ray = self.transform(object.get_shape().get_inverse_transform()?);
         object.intersect(local_ray)
     }
 
     // NOTE(Optimization): Is it faster to have a fixed size array here, and just not fill it up
     // if we don't get enough intersections?
     // And then live with the fact that we have a limit to how many intersections we can find
-    // Also, it seems unnesseccaaarryyy to have to check every object here if
-    // we can know that the ray is dang far away
+    // Call World::divide first so that far away objects get skipped by their bounds
     pub fn intersect_world(self, world: &World) -> Vec<Intersection> {
         let mut xs: Vec<Intersection> = vec![];
 
         let objects_iter = world.objects.iter();
         for o in objects_iter {
             if let Some(intersections) = self.intersect(o) {
-                for o_i in intersections.iter() {
-                    if let Some(i) = o_i {
-                        xs.push(*i)
-                    }
-                }
+                xs.extend(intersections);
             }
         }
 
@@ -41,8 +41,8 @@ mod ray_tests {
         let xs = ray.intersect(&sphere).unwrap();
 
         assert_eq!(xs.len(), 2);
-        assert_eq!(xs[0].unwrap().t, 4.0);
-        assert_eq!(xs[1].unwrap().t, 6.0);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 6.0);
     }
 
     #[test]
@@ -54,8 +54,8 @@ mod ray_tests {
         let xs = r.intersect(&s).unwrap();
 
         assert_eq!(xs.len(), 2);
-        assert_eq!(xs[0].unwrap().t, 5.0);
-        assert_eq!(xs[1].unwrap().t, 5.0);
+        assert_eq!(xs[0].t, 5.0);
+        assert_eq!(xs[1].t, 5.0);
     }
 
     #[test]
@@ -78,8 +78,8 @@ mod ray_tests {
         let xs = r.intersect(&s).unwrap();
 
         assert_eq!(xs.len(), 2);
-        assert_eq!(xs[0].unwrap().t, -1.0);
-        assert_eq!(xs[1].unwrap().t, 1.0);
+        assert_eq!(xs[0].t, -1.0);
+        assert_eq!(xs[1].t, 1.0);
     }
 
     #[test]
@@ -91,8 +91,8 @@ mod ray_tests {
         let xs = r.intersect(&s).unwrap();
 
         assert_eq!(xs.len(), 2);
-        assert_eq!(xs[0].unwrap().t, -6.0);
-        assert_eq!(xs[1].unwrap().t, -4.0);
+        assert_eq!(xs[0].t, -6.0);
+        assert_eq!(xs[1].t, -4.0);
     }
 
     #[test]
@@ -126,8 +126,8 @@ mod ray_tests {
         let xs = r.intersect(&s).unwrap();
 
         assert_eq!(xs.len(), 2);
-        assert_eq!(*xs[0].unwrap().object, s);
-        assert_eq!(*xs[1].unwrap().object, s);
+        assert_eq!(*xs[0].object, s);
+        assert_eq!(*xs[1].object, s);
     }
 
     #[test]
@@ -210,8 +210,8 @@ mod ray_tests {
         let xs = r.intersect(&s).unwrap();
 
         assert_eq!(xs.len(), 2);
-        assert_eq!(xs[0].unwrap().t, 3.);
-        assert_eq!(xs[1].unwrap().t, 7.);
+        assert_eq!(xs[0].t, 3.);
+        assert_eq!(xs[1].t, 7.);
     }
 
     #[test]
@@ -245,7 +245,8 @@ mod ray_tests {
         let shape = Sphere::new();
         let i = Intersection{
             t: 4.0,
-            object: &Drawables::Sphere(shape)
+            object: &Drawables::Sphere(shape),
+            uv: None
         };
 
         let comps = i.prepare_computations(r, None).unwrap();
@@ -264,7 +265,8 @@ mod ray_tests {
 
         let i = Intersection{
             t: 4.0,
-            object: &Drawables::Sphere(shape)
+            object: &Drawables::Sphere(shape),
+            uv: None
         };
 
         let comps = i.prepare_computations(r, None).unwrap();
@@ -279,7 +281,8 @@ mod ray_tests {
 
         let i = Intersection{
             t: 1.0,
-            object: &Drawables::Sphere(shape)
+            object: &Drawables::Sphere(shape),
+            uv: None
         };
 
         let comps = i.prepare_computations(r, None).unwrap();
@@ -302,7 +305,8 @@ mod ray_tests {
 
         let i = Intersection{
             t: 2.0_f64.sqrt(),
-            object: &Drawables::Plane(shape)
+            object: &Drawables::Plane(shape),
+            uv: None
         };
 
         let comps = i.prepare_computations(r, None).unwrap();
@@ -317,11 +321,12 @@ mod ray_tests {
         let mut a = Sphere::new();
         a.shape.material.transparency = 1.0;
         a.shape.material.refractive_index = 1.5;
-        a.shape.transform = Matrix4::new_translation(0., 0., 1.);
+        a.shape.set_transform(Matrix4::new_translation(0., 0., 1.));
 
         let i = Intersection{
             t: 5.0,
-            object: &Drawables::Sphere(a)
+            object: &Drawables::Sphere(a),
+            uv: None
         };
 
         let xs = vec![i];
@@ -0,0 +1,775 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::yaml::{YamlNode, YamlValue};
+use super::camera::{Adaptive, Camera, Sampling, ADAPTIVE_DEPTH};
+use super::world::{World, DIVIDE_THRESHOLD};
+use super::point_light::PointLight;
+use super::area_light::AreaLight;
+use super::spot_light::SpotLight;
+use super::directional_light::DirectionalLight;
+use super::lights::Lights;
+use super::material::Material;
+use super::patterns::{Fill, Patterns, Pattern};
+use super::texture::{Filtering, UvMapping, UvPattern};
+use super::noise::Fractal;
+use super::canvas::Canvas;
+use super::matrix::Matrix4;
+use super::tuple::Tuple;
+use super::color::Color;
+use super::sphere::Sphere;
+use super::plane::Plane;
+use super::cube::Cube;
+use super::cylinder::Cylinder;
+use super::cone::Cone;
+use super::triangle::Triangle;
+use super::group::Group;
+use super::csg::{Csg, CsgOperation};
+use super::obj_parser::ObjParser;
+use super::generics::{Drawable, Drawables};
+
+// Everything needed to render a picture
+#[allow(dead_code)]
+pub struct Scene {
+    pub camera: Camera,
+    pub world: World,
+}
+
+// The object types that can be added without a define
+const OBJECT_TYPES: [&str; 9] = [
+    "sphere", "plane", "cube", "cylinder", "cone", "triangle", "group", "obj", "csg",
+];
+
+#[allow(dead_code)]
+impl Scene {
+    // Reads a scene written in yaml, as a list of entries like
+    //
+    // - add: camera
+    //   width: 100
+    //   height: 100
+    //   field-of-view: 0.785
+    //   from: [0, 1.5, -5]
+    //   to: [0, 1, 0]
+    //   up: [0, 1, 0]
+    //
+    // - define: red
+    //   value:
+    //     color: [1, 0, 0]
+    //
+    // - add: sphere
+    //   material: red
+    //   transform:
+    //     - [scale, 0.5, 0.5, 0.5]
+    //     - [translate, 0, 1, 0]
+    //
+    // Errors are messages that start with the line they are about.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        Self::parse_in_dir(input, Path::new("."))
+    }
+
+    // Like parse, but obj files are looked for next to the scene file
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let input = match fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(_) => return Err(format!("could not read scene file {}", path)),
+        };
+        let dir = Path::new(path).parent().unwrap_or_else(|| Path::new("."));
+
+        Self::parse_in_dir(&input, dir)
+    }
+
+    fn parse_in_dir(input: &str, dir: &Path) -> Result<Self, String> {
+        let root = match YamlNode::parse(input)? {
+            Some(root) => root,
+            None => return Err(String::from("the scene is empty")),
+        };
+
+        let entries = match root.as_sequence() {
+            Some(entries) => entries,
+            None => return Err(error(&root, "a scene should be a list of add and define entries")),
+        };
+
+        let mut loader = SceneLoader {
+            dir: dir.to_path_buf(),
+            defines: HashMap::new(),
+            camera: None,
+            lights: vec![],
+            objects: vec![],
+            expanding: RefCell::new(vec![]),
+        };
+
+        for entry in entries.iter() {
+            loader.read_entry(entry)?;
+        }
+
+        let camera = match loader.camera {
+            Some(camera) => camera,
+            None => return Err(String::from("the scene has no camera")),
+        };
+
+        // Big meshes would otherwise test every triangle for every ray
+        let mut world = World {
+            lights: loader.lights,
+            objects: loader.objects,
+        };
+        world.divide(DIVIDE_THRESHOLD);
+
+        Ok(Self { camera, world })
+    }
+}
+
+fn error(node: &YamlNode, message: &str) -> String {
+    format!("line {}: {}", node.line, message)
+}
+
+fn check_keys(node: &YamlNode, allowed: &[&str]) -> Result<(), String> {
+    let entries = match node.as_mapping() {
+        Some(entries) => entries,
+        None => return Err(error(node, "expected a mapping")),
+    };
+
+    for (key, _) in entries.iter() {
+        match key.as_str() {
+            Some(k) if allowed.contains(&k) => (),
+            Some(k) => return Err(error(key, &format!("unknown key '{}'", k))),
+            None => return Err(error(key, "keys should be plain names")),
+        }
+    }
+    Ok(())
+}
+
+fn required<'a>(node: &'a YamlNode, key: &str) -> Result<&'a YamlNode, String> {
+    match node.get(key) {
+        Some(value) => Ok(value),
+        None => Err(error(node, &format!("missing key '{}'", key))),
+    }
+}
+
+fn read_str(node: &YamlNode) -> Result<&str, String> {
+    match node.as_str() {
+        Some(s) => Ok(s),
+        None => Err(error(node, "expected a name")),
+    }
+}
+
+fn read_number(node: &YamlNode) -> Result<f64, String> {
+    match node.as_f64() {
+        Some(n) => Ok(n),
+        None => Err(error(node, "expected a number")),
+    }
+}
+
+fn read_size(node: &YamlNode) -> Result<usize, String> {
+    match node.as_str().and_then(|s| s.parse::<usize>().ok()) {
+        Some(n) if n > 0 => Ok(n),
+        _ => Err(error(node, "expected a positive whole number")),
+    }
+}
+
+fn read_seed(node: &YamlNode) -> Result<u64, String> {
+    match node.as_str().and_then(|s| s.parse::<u64>().ok()) {
+        Some(n) => Ok(n),
+        _ => Err(error(node, "expected a whole number")),
+    }
+}
+
+fn read_bool(node: &YamlNode) -> Result<bool, String> {
+    match node.as_str() {
+        Some("true") => Ok(true),
+        Some("false") => Ok(false),
+        _ => Err(error(node, "expected true or false")),
+    }
+}
+
+fn read_triple(node: &YamlNode) -> Result<(f64, f64, f64), String> {
+    let values = match node.as_sequence() {
+        Some(values) if values.len() == 3 => values,
+        _ => return Err(error(node, "expected a list of three numbers")),
+    };
+
+    Ok((read_number(&values[0])?, read_number(&values[1])?, read_number(&values[2])?))
+}
+
+fn read_two_colors(node: &YamlNode) -> Result<(Color, Color), String> {
+    match node.as_sequence() {
+        Some(c) if c.len() == 2 => Ok((read_color(&c[0])?, read_color(&c[1])?)),
+        _ => Err(error(node, "expected a list of two colors")),
+    }
+}
+
+fn read_point(node: &YamlNode) -> Result<Tuple, String> {
+    let (x, y, z) = read_triple(node)?;
+    Ok(Tuple::new_point(x, y, z))
+}
+
+fn read_vector(node: &YamlNode) -> Result<Tuple, String> {
+    let (x, y, z) = read_triple(node)?;
+    Ok(Tuple::new_vector(x, y, z))
+}
+
+fn read_color(node: &YamlNode) -> Result<Color, String> {
+    let (r, g, b) = read_triple(node)?;
+    Ok(Color::new(r, g, b))
+}
+
+// Keys in extra replace the ones with the same name in base
+fn merge(base: &YamlNode, extra: &YamlNode) -> YamlNode {
+    let mut entries = base.as_mapping().cloned().unwrap_or_default();
+    for (key, value) in extra.as_mapping().into_iter().flatten() {
+        match entries.iter_mut().find(|(k, _)| k.as_str() == key.as_str()) {
+            Some(entry) => entry.1 = value.clone(),
+            None => entries.push((key.clone(), value.clone())),
+        }
+    }
+
+    YamlNode {
+        value: YamlValue::Mapping(entries),
+        line: extra.line,
+    }
+}
+
+struct SceneLoader {
+    dir: PathBuf,
+    defines: HashMap<String, YamlNode>,
+    camera: Option<Camera>,
+    lights: Vec<Lights>,
+    objects: Vec<Drawables>,
+    // The defines being read right now, to catch the ones that refer to themselves
+    expanding: RefCell<Vec<String>>,
+}
+
+// Takes the name back off of SceneLoader::expanding once the define has been read
+struct Expanding<'a> {
+    names: &'a RefCell<Vec<String>>,
+}
+
+impl Drop for Expanding<'_> {
+    fn drop(&mut self) {
+        self.names.borrow_mut().pop();
+    }
+}
+
+impl SceneLoader {
+    fn read_entry(&mut self, entry: &YamlNode) -> Result<(), String> {
+        if entry.get("define").is_some() {
+            return self.read_define(entry);
+        }
+
+        let add = match entry.get("add") {
+            Some(add) => add,
+            None => return Err(error(entry, "expected an add or define entry")),
+        };
+
+        match read_str(add)? {
+            "camera" => self.camera = Some(self.read_camera(entry)?),
+            "light" => self.lights.push(self.read_light(entry)?),
+            _ => {
+                let object = self.read_object(entry)?;
+                self.objects.push(object);
+            },
+        }
+        Ok(())
+    }
+
+    fn read_define(&mut self, entry: &YamlNode) -> Result<(), String> {
+        check_keys(entry, &["define", "extend", "value"])?;
+        let name = read_str(required(entry, "define")?)?.to_string();
+        let (value, _) = self.resolve(required(entry, "value")?)?;
+
+        let value = match entry.get("extend") {
+            Some(extend) => {
+                let (base, _) = self.resolve(extend)?;
+                if base.as_mapping().is_none() || value.as_mapping().is_none() {
+                    return Err(error(extend, "only mappings can be extended"));
+                }
+                merge(base, value)
+            },
+            None => value.clone(),
+        };
+
+        // Objects are expanded right away, so that a define can't end up
+        // referring to itself
+        let value = if value.get("add").is_some() {
+            self.expand_object(&value)?
+        } else {
+            value
+        };
+
+        self.defines.insert(name, value);
+        Ok(())
+    }
+
+    // Looks up a name that was given to a define, anything else is returned as is.
+    // The define counts as being read for as long as the Expanding is kept.
+    fn resolve<'a>(&'a self, node: &'a YamlNode) -> Result<(&'a YamlNode, Option<Expanding<'a>>), String> {
+        let name = match node.as_str() {
+            Some(name) => name,
+            None => return Ok((node, None)),
+        };
+        let value = match self.defines.get(name) {
+            Some(value) => value,
+            None => return Err(error(node, &format!("unknown define '{}'", name))),
+        };
+
+        if self.expanding.borrow().iter().any(|n| n == name) {
+            return Err(error(node, &format!("define '{}' refers to itself", name)));
+        }
+        self.expanding.borrow_mut().push(name.to_string());
+        Ok((value, Some(Expanding { names: &self.expanding })))
+    }
+
+    fn read_camera(&self, entry: &YamlNode) -> Result<Camera, String> {
+        check_keys(entry, &[
+            "add", "width", "height", "field-of-view", "from", "to", "up", "samples", "sampling",
+            "adaptive", "aperture", "focal-distance",
+        ])?;
+
+        let mut camera = Camera::new(
+            read_size(required(entry, "width")?)?,
+            read_size(required(entry, "height")?)?,
+            read_number(required(entry, "field-of-view")?)?,
+        );
+        camera.transform = Matrix4::new_view_transform(
+            read_point(required(entry, "from")?)?,
+            read_point(required(entry, "to")?)?,
+            read_vector(required(entry, "up")?)?,
+        );
+
+        if let Some(samples) = entry.get("samples") {
+            camera.samples = read_size(samples)?;
+        }
+        if let Some(sampling) = entry.get("sampling") {
+            camera.sampling = match read_str(sampling)? {
+                "grid" => Sampling::Grid,
+                "jittered" => Sampling::Jittered,
+                "random" => Sampling::Random,
+                name => return Err(error(sampling, &format!("unknown sampling '{}'", name))),
+            };
+        }
+        if let Some(adaptive) = entry.get("adaptive") {
+            check_keys(adaptive, &["threshold", "max-depth"])?;
+            camera.adaptive = Some(Adaptive {
+                threshold: read_number(required(adaptive, "threshold")?)?,
+                max_depth: match adaptive.get("max-depth") {
+                    Some(max_depth) => read_size(max_depth)?,
+                    None => ADAPTIVE_DEPTH,
+                },
+            });
+        }
+        if let Some(aperture) = entry.get("aperture") {
+            camera.aperture = match read_number(aperture)? {
+                n if n >= 0. => n,
+                _ => return Err(error(aperture, "the aperture can't be negative")),
+            };
+        }
+        if let Some(focal_distance) = entry.get("focal-distance") {
+            camera.focal_distance = match read_number(focal_distance)? {
+                n if n > 0. => n,
+                _ => return Err(error(focal_distance, "the focal distance has to be positive")),
+            };
+        }
+
+        Ok(camera)
+    }
+
+    // Area lights are given by a corner instead of a position, spot lights
+    // have a direction as well as a position and directional lights only
+    // have the direction
+    fn read_light(&self, entry: &YamlNode) -> Result<Lights, String> {
+        if entry.get("corner").is_some() {
+            return self.read_area_light(entry);
+        }
+
+        if entry.get("direction").is_some() && entry.get("at").is_none() {
+            check_keys(entry, &["add", "direction", "intensity"])?;
+
+            let light = DirectionalLight::new(
+                read_vector(required(entry, "direction")?)?,
+                read_color(required(entry, "intensity")?)?,
+            ).map_err(|e| error(entry, e))?;
+            return Ok(Lights::Directional(light));
+        }
+
+        if entry.get("direction").is_some() {
+            check_keys(entry, &["add", "at", "direction", "inner-angle", "outer-angle", "intensity"])?;
+
+            let outer_angle = read_number(required(entry, "outer-angle")?)?;
+            let inner_angle = match entry.get("inner-angle") {
+                Some(inner_angle) => read_number(inner_angle)?,
+                None => outer_angle,
+            };
+            let light = SpotLight::new(
+                read_point(required(entry, "at")?)?,
+                read_vector(required(entry, "direction")?)?,
+                inner_angle,
+                outer_angle,
+                read_color(required(entry, "intensity")?)?,
+            ).map_err(|e| error(entry, e))?;
+            return Ok(Lights::Spot(light));
+        }
+
+        check_keys(entry, &["add", "at", "intensity"])?;
+
+        let light = PointLight::new(
+            read_point(required(entry, "at")?)?,
+            read_color(required(entry, "intensity")?)?,
+        ).map_err(|e| error(entry, e))?;
+        Ok(Lights::Point(light))
+    }
+
+    fn read_area_light(&self, entry: &YamlNode) -> Result<Lights, String> {
+        check_keys(entry, &["add", "corner", "uvec", "usteps", "vvec", "vsteps", "jitter", "intensity"])?;
+
+        let mut light = AreaLight::new(
+            read_point(required(entry, "corner")?)?,
+            read_vector(required(entry, "uvec")?)?,
+            read_size(required(entry, "usteps")?)?,
+            read_vector(required(entry, "vvec")?)?,
+            read_size(required(entry, "vsteps")?)?,
+            read_color(required(entry, "intensity")?)?,
+        ).map_err(|e| error(entry, e))?;
+        if let Some(jitter) = entry.get("jitter") {
+            light.jitter = read_bool(jitter)?;
+        }
+        Ok(Lights::Area(light))
+    }
+
+    fn read_transform(&self, node: &YamlNode) -> Result<Matrix4, String> {
+        let (node, _expanding) = self.resolve(node)?;
+        let steps = match node.as_sequence() {
+            Some(steps) => steps,
+            None => return Err(error(node, "expected a list of transforms")),
+        };
+
+        // Each step is applied after the ones above it
+        let mut transform = Matrix4::new_identity();
+        for step in steps.iter() {
+            let step_transform = match step.value {
+                YamlValue::Scalar(_) => self.read_transform(step)?,
+                _ => Self::read_transform_step(step)?,
+            };
+            transform = step_transform * transform;
+        }
+
+        Ok(transform)
+    }
+
+    fn read_transform_step(step: &YamlNode) -> Result<Matrix4, String> {
+        let values = match step.as_sequence() {
+            Some(values) if !values.is_empty() => values,
+            _ => return Err(error(step, "expected a transform like [translate, 1, 2, 3]")),
+        };
+
+        let name = read_str(&values[0])?;
+        let args = values[1..].iter().map(read_number).collect::<Result<Vec<f64>, String>>()?;
+
+        let expected = match name {
+            "translate" | "scale" => 3,
+            "rotate-x" | "rotate-y" | "rotate-z" => 1,
+            "shear" => 6,
+            _ => return Err(error(&values[0], &format!("unknown transform '{}'", name))),
+        };
+        if args.len() != expected {
+            return Err(error(step, &format!("{} takes {} values", name, expected)));
+        }
+
+        Ok(match name {
+            "translate" => Matrix4::new_translation(args[0], args[1], args[2]),
+            "scale" => Matrix4::new_scaling(args[0], args[1], args[2]),
+            "rotate-x" => Matrix4::new_rotation_x(args[0]),
+            "rotate-y" => Matrix4::new_rotation_y(args[0]),
+            "rotate-z" => Matrix4::new_rotation_z(args[0]),
+            _ => Matrix4::new_shearing(args[0], args[1], args[2], args[3], args[4], args[5]),
+        })
+    }
+
+    fn read_material(&self, node: &YamlNode) -> Result<Material, String> {
+        let (node, _expanding) = self.resolve(node)?;
+        check_keys(node, &[
+            "color", "ambient", "diffuse", "specular", "shininess",
+            "reflective", "transparency", "refractive-index", "pattern",
+        ])?;
+
+        let mut material: Material = Default::default();
+        for (key, value) in node.as_mapping().into_iter().flatten() {
+            match key.as_str() {
+                Some("color") => material.color = read_color(value)?,
+                Some("ambient") => material.ambient = read_number(value)?,
+                Some("diffuse") => material.diffuse = read_number(value)?,
+                Some("specular") => material.specular = read_number(value)?,
+                Some("shininess") => material.shininess = read_number(value)?,
+                Some("reflective") => material.reflective = read_number(value)?,
+                Some("transparency") => material.transparency = read_number(value)?,
+                Some("refractive-index") => material.refractive_index = read_number(value)?,
+                Some("pattern") => material.pattern = Some(self.read_pattern(value)?),
+                _ => (),
+            }
+        }
+
+        Ok(material)
+    }
+
+    fn read_pattern(&self, node: &YamlNode) -> Result<Patterns, String> {
+        let (node, _expanding) = self.resolve(node)?;
+
+        let pattern_type = required(node, "type")?;
+        let mut pattern = match read_str(pattern_type)? {
+            "map" => {
+                check_keys(node, &["type", "mapping", "uv-pattern", "transform"])?;
+
+                let mapping = required(node, "mapping")?;
+                let mapping = match read_str(mapping)? {
+                    "spherical" => UvMapping::Spherical,
+                    "planar" => UvMapping::Planar,
+                    "cylindrical" => UvMapping::Cylindrical,
+                    "cube" => UvMapping::Cube,
+                    name => return Err(error(mapping, &format!("unknown mapping '{}'", name))),
+                };
+                Patterns::new_texture_map(self.read_uv_pattern(required(node, "uv-pattern")?)?, mapping)
+            },
+            "cube-map" => {
+                check_keys(node, &["type", "left", "right", "front", "back", "up", "down", "transform"])?;
+
+                Patterns::new_cube_map(
+                    self.read_uv_pattern(required(node, "left")?)?,
+                    self.read_uv_pattern(required(node, "right")?)?,
+                    self.read_uv_pattern(required(node, "front")?)?,
+                    self.read_uv_pattern(required(node, "back")?)?,
+                    self.read_uv_pattern(required(node, "up")?)?,
+                    self.read_uv_pattern(required(node, "down")?)?,
+                )
+            },
+            "perturbed" => {
+                check_keys(node, &["type", "pattern", "noise", "octaves", "scale", "seed", "transform"])?;
+
+                let fractal = match node.get("noise") {
+                    Some(noise) => match read_str(noise)? {
+                        "fbm" => Fractal::Fbm,
+                        "turbulence" => Fractal::Turbulence,
+                        name => return Err(error(noise, &format!("unknown noise '{}'", name))),
+                    },
+                    None => Fractal::Fbm,
+                };
+                Patterns::new_perturbed(
+                    self.read_pattern(required(node, "pattern")?)?,
+                    fractal,
+                    match node.get("octaves") {
+                        Some(octaves) => read_size(octaves)?,
+                        None => 1,
+                    },
+                    match node.get("scale") {
+                        Some(scale) => read_number(scale)?,
+                        None => 0.2,
+                    },
+                    match node.get("seed") {
+                        Some(seed) => read_seed(seed)?,
+                        None => 0,
+                    },
+                )
+            },
+            "blend" => {
+                check_keys(node, &["type", "patterns", "weight", "transform"])?;
+
+                let patterns = required(node, "patterns")?;
+                let (a, b) = match patterns.as_sequence() {
+                    Some(p) if p.len() == 2 => (self.read_pattern(&p[0])?, self.read_pattern(&p[1])?),
+                    _ => return Err(error(patterns, "expected a list of two patterns")),
+                };
+                let weight = match node.get("weight") {
+                    Some(weight) => read_number(weight)?,
+                    None => 0.5,
+                };
+                Patterns::new_blended(a, b, weight)
+            },
+            name => {
+                check_keys(node, &["type", "colors", "transform"])?;
+
+                let (a, b) = self.read_two_fills(required(node, "colors")?)?;
+                match name {
+                    "stripes" => Patterns::new_stripe(a, b),
+                    "gradient" => Patterns::new_gradient(a, b),
+                    "rings" => Patterns::new_ring(a, b),
+                    "checkers" => Patterns::new_checker(a, b),
+                    _ => return Err(error(pattern_type, &format!("unknown pattern '{}'", name))),
+                }
+            },
+        };
+
+        if let Some(transform) = node.get("transform") {
+            pattern.set_transform(self.read_transform(transform)?);
+        }
+
+        Ok(pattern)
+    }
+
+    // Either a color, or a pattern to use in its place
+    fn read_fill(&self, node: &YamlNode) -> Result<Fill, String> {
+        let (node, _expanding) = self.resolve(node)?;
+        match node.as_sequence() {
+            Some(_) => Ok(Fill::Color(read_color(node)?)),
+            None => Ok(self.read_pattern(node)?.into()),
+        }
+    }
+
+    fn read_two_fills(&self, node: &YamlNode) -> Result<(Fill, Fill), String> {
+        match node.as_sequence() {
+            Some(f) if f.len() == 2 => Ok((self.read_fill(&f[0])?, self.read_fill(&f[1])?)),
+            _ => Err(error(node, "expected a list of two colors or patterns")),
+        }
+    }
+
+    fn read_uv_pattern(&self, node: &YamlNode) -> Result<UvPattern, String> {
+        let (node, _expanding) = self.resolve(node)?;
+
+        let pattern_type = required(node, "type")?;
+        match read_str(pattern_type)? {
+            "checkers" => {
+                check_keys(node, &["type", "width", "height", "colors"])?;
+
+                let (a, b) = read_two_colors(required(node, "colors")?)?;
+                Ok(UvPattern::new_checkers(
+                    read_number(required(node, "width")?)?,
+                    read_number(required(node, "height")?)?,
+                    a,
+                    b,
+                ))
+            },
+            "image" => {
+                check_keys(node, &["type", "file", "filtering"])?;
+
+                let file = required(node, "file")?;
+                let path = self.dir.join(read_str(file)?);
+                let image = Canvas::load(&path.to_string_lossy()).map_err(|e| error(file, &e))?;
+
+                let filtering = match node.get("filtering") {
+                    Some(filtering) => match read_str(filtering)? {
+                        "nearest" => Filtering::Nearest,
+                        "bilinear" => Filtering::Bilinear,
+                        name => return Err(error(filtering, &format!("unknown filtering '{}'", name))),
+                    },
+                    None => Filtering::Bilinear,
+                };
+                Ok(UvPattern::new_image(image, filtering))
+            },
+            "align-check" => {
+                check_keys(node, &["type", "main", "ul", "ur", "bl", "br"])?;
+
+                Ok(UvPattern::new_align_check(
+                    read_color(required(node, "main")?)?,
+                    read_color(required(node, "ul")?)?,
+                    read_color(required(node, "ur")?)?,
+                    read_color(required(node, "bl")?)?,
+                    read_color(required(node, "br")?)?,
+                ))
+            },
+            name => Err(error(pattern_type, &format!("unknown uv pattern '{}'", name))),
+        }
+    }
+
+    // Turns `add: some-define` into the defined object, with any keys
+    // given here replacing the defined ones
+    fn expand_object(&self, entry: &YamlNode) -> Result<YamlNode, String> {
+        let add = required(entry, "add")?;
+        let name = read_str(add)?;
+        if OBJECT_TYPES.contains(&name) {
+            return Ok(entry.clone());
+        }
+
+        match self.defines.get(name) {
+            Some(define) if define.get("add").is_some() => {
+                let mut overrides = entry.clone();
+                if let YamlValue::Mapping(entries) = &mut overrides.value {
+                    entries.retain(|(k, _)| k.as_str() != Some("add"));
+                }
+                Ok(merge(define, &overrides))
+            },
+            _ => Err(error(add, &format!("unknown object type '{}'", name))),
+        }
+    }
+
+    fn read_object(&self, entry: &YamlNode) -> Result<Drawables, String> {
+        let entry = self.expand_object(entry)?;
+        let kind = read_str(required(&entry, "add")?)?;
+
+        let allowed: &[&str] = match kind {
+            "cylinder" | "cone" => &["add", "transform", "material", "min", "max", "closed"],
+            "triangle" => &["add", "transform", "material", "p1", "p2", "p3"],
+            "group" => &["add", "transform", "children"],
+            "obj" => &["add", "transform", "file"],
+            "csg" => &["add", "transform", "operation", "left", "right"],
+            _ => &["add", "transform", "material"],
+        };
+        check_keys(&entry, allowed)?;
+
+        let mut object = match kind {
+            "sphere" => Drawables::Sphere(Sphere::new()),
+            "plane" => Drawables::Plane(Plane::new()),
+            "cube" => Drawables::Cube(Cube::new()),
+            "cylinder" => {
+                let mut cylinder = Cylinder::new();
+                if let Some(min) = entry.get("min") { cylinder.minimum = read_number(min)? }
+                if let Some(max) = entry.get("max") { cylinder.maximum = read_number(max)? }
+                if let Some(closed) = entry.get("closed") { cylinder.closed = read_bool(closed)? }
+                Drawables::Cylinder(cylinder)
+            },
+            "cone" => {
+                let mut cone = Cone::new();
+                if let Some(min) = entry.get("min") { cone.minimum = read_number(min)? }
+                if let Some(max) = entry.get("max") { cone.maximum = read_number(max)? }
+                if let Some(closed) = entry.get("closed") { cone.closed = read_bool(closed)? }
+                Drawables::Cone(cone)
+            },
+            "triangle" => Drawables::Triangle(Triangle::new(
+                read_point(required(&entry, "p1")?)?,
+                read_point(required(&entry, "p2")?)?,
+                read_point(required(&entry, "p3")?)?,
+            )),
+            "group" => {
+                let mut group = Group::new();
+                if let Some(children) = entry.get("children") {
+                    let children = match children.as_sequence() {
+                        Some(children) => children,
+                        None => return Err(error(children, "expected a list of objects")),
+                    };
+                    for child in children.iter() {
+                        group.add_child(self.read_object(child)?);
+                    }
+                }
+                Drawables::Group(group)
+            },
+            "obj" => {
+                let file = required(&entry, "file")?;
+                let path = self.dir.join(read_str(file)?);
+                match ObjParser::from_file(&path.to_string_lossy()) {
+                    Ok(parser) => Drawables::Group(parser.to_group()),
+                    Err(e) => return Err(error(file, e)),
+                }
+            },
+            _ => {
+                let operation = required(&entry, "operation")?;
+                let operation = match read_str(operation)? {
+                    "union" => CsgOperation::Union,
+                    "intersection" => CsgOperation::Intersection,
+                    "difference" => CsgOperation::Difference,
+                    name => return Err(error(operation, &format!("unknown csg operation '{}'", name))),
+                };
+                Drawables::Csg(Csg::new(
+                    operation,
+                    self.read_object(required(&entry, "left")?)?,
+                    self.read_object(required(&entry, "right")?)?,
+                ))
+            },
+        };
+
+        if let Some(material) = entry.get("material") {
+            object.get_shape_mut().material = self.read_material(material)?;
+        }
+        if let Some(transform) = entry.get("transform") {
+            object.set_transform(self.read_transform(transform)?);
+        }
+
+        Ok(object)
+    }
+}
@@ -0,0 +1,579 @@
+#[cfg(test)]
+
+mod scene_tests {
+    use std::f64::consts::PI;
+    use crate::scene::*;
+    use crate::camera::{Adaptive, Sampling};
+    use crate::tuple::Tuple;
+    use crate::color::Color;
+    use crate::matrix::Matrix4;
+    use crate::material::Material;
+    use crate::patterns::{Patterns, Pattern};
+    use crate::texture::{Filtering, UvMapping, UvPattern};
+    use crate::noise::Fractal;
+    use crate::canvas::{BitDepth, Canvas};
+    use crate::point_light::PointLight;
+    use crate::area_light::AreaLight;
+    use crate::spot_light::SpotLight;
+    use crate::directional_light::DirectionalLight;
+    use crate::lights::Lights;
+    use crate::csg::CsgOperation;
+    use crate::generics::{Drawable, Drawables};
+
+    const CAMERA: &str = "- add: camera
+  width: 100
+  height: 50
+  field-of-view: 0.785
+  from: [0, 0, -5]
+  to: [0, 0, 0]
+  up: [0, 1, 0]
+";
+
+    fn with_camera(input: &str) -> Result<Scene, String> {
+        Scene::parse(&format!("{}\n{}", CAMERA, input))
+    }
+
+    #[test]
+    fn read_camera_and_light() {
+        let scene = with_camera("- add: light
+  at: [-10, 10, -10]
+  intensity: [1, 0.5, 1]
+").unwrap();
+
+        assert_eq!(scene.camera.h_size, 100);
+        assert_eq!(scene.camera.v_size, 50);
+        assert_eq!(scene.camera.field_of_view, 0.785);
+        assert_eq!(scene.camera.transform, Matrix4::new_view_transform(
+            Tuple::new_point(0., 0., -5.),
+            Tuple::new_point(0., 0., 0.),
+            Tuple::new_vector(0., 1., 0.),
+        ));
+        assert_eq!(scene.world.lights, vec![
+            Lights::Point(PointLight::new(Tuple::new_point(-10., 10., -10.), Color::new(1., 0.5, 1.)).unwrap())
+        ]);
+    }
+
+    #[test]
+    fn read_camera_sampling() {
+        let scene = Scene::parse("- add: camera
+  width: 10
+  height: 10
+  field-of-view: 1
+  from: [0, 0, -5]
+  to: [0, 0, 0]
+  up: [0, 1, 0]
+  samples: 4
+  sampling: jittered
+").unwrap();
+
+        assert_eq!(scene.camera.samples, 4);
+        assert_eq!(scene.camera.sampling, Sampling::Jittered);
+    }
+
+    #[test]
+    fn read_camera_adaptive() {
+        let scene = Scene::parse("- add: camera
+  width: 10
+  height: 10
+  field-of-view: 1
+  from: [0, 0, -5]
+  to: [0, 0, 0]
+  up: [0, 1, 0]
+  adaptive:
+    threshold: 0.1
+    max-depth: 3
+").unwrap();
+
+        assert_eq!(scene.camera.adaptive, Some(Adaptive { threshold: 0.1, max_depth: 3 }));
+    }
+
+    #[test]
+    fn read_camera_lens() {
+        let camera = "- add: camera
+  width: 10
+  height: 10
+  field-of-view: 1
+  from: [0, 0, -5]
+  to: [0, 0, 0]
+  up: [0, 1, 0]
+";
+        let scene = Scene::parse(&format!("{}  aperture: 0.25\n  focal-distance: 5\n", camera)).unwrap();
+        assert_eq!((scene.camera.aperture, scene.camera.focal_distance), (0.25, 5.));
+
+        let scene = Scene::parse(&format!("{}  aperture: -1\n", camera));
+        assert_eq!(scene.err(), Some(String::from("line 8: the aperture can't be negative")));
+
+        let scene = Scene::parse(&format!("{}  focal-distance: 0\n", camera));
+        assert_eq!(scene.err(), Some(String::from("line 8: the focal distance has to be positive")));
+    }
+
+    #[test]
+    fn read_area_light() {
+        let scene = with_camera("- add: light
+  corner: [-1, 2, 4]
+  uvec: [2, 0, 0]
+  usteps: 4
+  vvec: [0, 2, 0]
+  vsteps: 2
+  jitter: true
+  intensity: [1.5, 1.5, 1.5]
+").unwrap();
+
+        let mut light = AreaLight::new(
+            Tuple::new_point(-1., 2., 4.),
+            Tuple::new_vector(2., 0., 0.),
+            4,
+            Tuple::new_vector(0., 2., 0.),
+            2,
+            Color::new(1.5, 1.5, 1.5),
+        ).unwrap();
+        light.jitter = true;
+        assert_eq!(scene.world.lights, vec![Lights::Area(light)]);
+    }
+
+    #[test]
+    fn read_directional_light() {
+        let scene = with_camera("- add: light
+  direction: [0, -1, 1]
+  intensity: [1, 1, 0.9]
+").unwrap();
+
+        assert_eq!(scene.world.lights, vec![Lights::Directional(DirectionalLight::new(
+            Tuple::new_vector(0., -1., 1.),
+            Color::new(1., 1., 0.9),
+        ).unwrap())]);
+    }
+
+    #[test]
+    fn read_spot_light() {
+        let scene = with_camera("- add: light
+  at: [0, 10, 0]
+  direction: [0, -1, 0]
+  inner-angle: 0.2
+  outer-angle: 0.4
+  intensity: [1, 1, 1]
+- add: light
+  at: [0, 10, 0]
+  direction: [0, -1, 0]
+  outer-angle: 0.4
+  intensity: [1, 1, 1]
+").unwrap();
+
+        let light = |inner_angle| Lights::Spot(SpotLight::new(
+            Tuple::new_point(0., 10., 0.),
+            Tuple::new_vector(0., -1., 0.),
+            inner_angle,
+            0.4,
+            Color::new(1., 1., 1.),
+        ).unwrap());
+        assert_eq!(scene.world.lights, vec![light(0.2), light(0.4)]);
+    }
+
+    #[test]
+    fn read_object_with_transform_and_material() {
+        let scene = with_camera("- add: sphere
+  transform:
+    - [rotate-y, 1.5707963267948966]
+    - [scale, 2, 2, 2]
+    - [translate, 1, 2, 3]
+  material:
+    color: [1, 0, 0]
+    diffuse: 0.5
+    refractive-index: 1.5
+").unwrap();
+
+        let s = &scene.world.objects[0];
+        assert_eq!(s.get_transform(),
+            Matrix4::new_translation(1., 2., 3.) *
+            Matrix4::new_scaling(2., 2., 2.) *
+            Matrix4::new_rotation_y(PI / 2.)
+        );
+        assert_eq!(s.get_shape().material, Material {
+            color: Color::new(1., 0., 0.),
+            diffuse: 0.5,
+            refractive_index: 1.5,
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn read_every_object_type() {
+        let scene = with_camera("- add: plane
+- add: cube
+- add: cylinder
+  min: -1
+  max: 2
+  closed: true
+- add: cone
+- add: triangle
+  p1: [0, 1, 0]
+  p2: [-1, 0, 0]
+  p3: [1, 0, 0]
+- add: group
+  transform:
+    - [translate, 0, 1, 0]
+  children:
+    - add: sphere
+    - add: cube
+- add: csg
+  operation: difference
+  left:
+    add: cube
+  right:
+    add: sphere
+").unwrap();
+
+        let objects = &scene.world.objects;
+        assert_eq!(objects.len(), 7);
+        match &objects[2] {
+            Drawables::Cylinder(c) => {
+                assert_eq!((c.minimum, c.maximum, c.closed), (-1., 2., true));
+            },
+            _ => panic!("expected a cylinder"),
+        }
+        match &objects[5] {
+            Drawables::Group(g) => {
+                assert_eq!(g.children().len(), 2);
+                assert_eq!(g.children()[0].get_shape().get_parent_transform(), Matrix4::new_translation(0., 1., 0.));
+            },
+            _ => panic!("expected a group"),
+        }
+        match &objects[6] {
+            Drawables::Csg(c) => assert_eq!(c.operation, CsgOperation::Difference),
+            _ => panic!("expected a csg"),
+        }
+    }
+
+    #[test]
+    fn read_texture_map() {
+        let scene = with_camera("- add: sphere
+  material:
+    pattern:
+      type: map
+      mapping: spherical
+      uv-pattern:
+        type: checkers
+        width: 16
+        height: 8
+        colors:
+          - [0, 0, 0]
+          - [1, 1, 1]
+").unwrap();
+
+        let checkers = UvPattern::new_checkers(16., 8., Color::new(0., 0., 0.), Color::new(1., 1., 1.));
+        let expected = Patterns::new_texture_map(checkers, UvMapping::Spherical);
+        assert_eq!(scene.world.objects[0].get_shape().material.pattern, Some(expected));
+    }
+
+    #[test]
+    fn read_perturbed_pattern() {
+        let scene = with_camera("- add: plane
+  material:
+    pattern:
+      type: perturbed
+      noise: turbulence
+      octaves: 4
+      scale: 0.5
+      seed: 0
+      transform:
+        - [scale, 2, 2, 2]
+      pattern:
+        type: stripes
+        colors:
+          - [1, 1, 1]
+          - [0, 0, 0]
+- add: plane
+  material:
+    pattern:
+      type: perturbed
+      pattern:
+        type: rings
+        colors:
+          - [1, 1, 1]
+          - [0, 0, 0]
+").unwrap();
+
+        let white = Color::new(1., 1., 1.);
+        let black = Color::new(0., 0., 0.);
+        let mut marble = Patterns::new_perturbed(Patterns::new_stripe(white, black), Fractal::Turbulence, 4, 0.5, 0);
+        marble.set_transform(Matrix4::new_scaling(2., 2., 2.));
+        assert_eq!(scene.world.objects[0].get_shape().material.pattern, Some(marble));
+
+        let wood = Patterns::new_perturbed(Patterns::new_ring(white, black), Fractal::Fbm, 1, 0.2, 0);
+        assert_eq!(scene.world.objects[1].get_shape().material.pattern, Some(wood));
+    }
+
+    #[test]
+    fn bad_perturbed_pattern() {
+        let result = with_camera("- add: plane
+  material:
+    pattern:
+      type: perturbed
+      noise: lumpy
+      pattern:
+        type: stripes
+        colors:
+          - [1, 1, 1]
+          - [0, 0, 0]
+");
+        assert_eq!(result.err(), Some(String::from("line 13: unknown noise 'lumpy'")));
+    }
+
+    #[test]
+    fn read_nested_patterns() {
+        let scene = with_camera("- define: thin-stripes
+  value:
+    type: stripes
+    colors:
+      - [1, 1, 1]
+      - [1, 0, 0]
+    transform:
+      - [scale, 0.5, 0.5, 0.5]
+- add: plane
+  material:
+    pattern:
+      type: checkers
+      colors:
+        - thin-stripes
+        - [0, 0, 0]
+- add: plane
+  material:
+    pattern:
+      type: blend
+      weight: 0.25
+      patterns:
+        - thin-stripes
+        - type: rings
+          colors:
+            - [0, 0, 1]
+            - [0, 1, 0]
+").unwrap();
+
+        let mut stripes = Patterns::new_stripe(Color::new(1., 1., 1.), Color::new(1., 0., 0.));
+        stripes.set_transform(Matrix4::new_scaling(0.5, 0.5, 0.5));
+        let rings = Patterns::new_ring(Color::new(0., 0., 1.), Color::new(0., 1., 0.));
+
+        let checkers = Patterns::new_checker(stripes.clone(), Color::new(0., 0., 0.));
+        assert_eq!(scene.world.objects[0].get_shape().material.pattern, Some(checkers));
+
+        let blend = Patterns::new_blended(stripes, rings, 0.25);
+        assert_eq!(scene.world.objects[1].get_shape().material.pattern, Some(blend));
+    }
+
+    #[test]
+    fn read_cube_map() {
+        let scene = with_camera("- define: corners
+  value:
+    type: align-check
+    main: [1, 1, 1]
+    ul: [1, 0, 0]
+    ur: [1, 1, 0]
+    bl: [0, 1, 0]
+    br: [0, 1, 1]
+- define: squares
+  value:
+    type: checkers
+    width: 2
+    height: 2
+    colors:
+      - [0, 0, 0]
+      - [1, 1, 1]
+- add: cube
+  material:
+    pattern:
+      type: cube-map
+      left: corners
+      right: squares
+      front: corners
+      back: squares
+      up: corners
+      down: squares
+").unwrap();
+
+        let corners = UvPattern::new_align_check(
+            Color::new(1., 1., 1.),
+            Color::new(1., 0., 0.),
+            Color::new(1., 1., 0.),
+            Color::new(0., 1., 0.),
+            Color::new(0., 1., 1.),
+        );
+        let squares = UvPattern::new_checkers(2., 2., Color::new(0., 0., 0.), Color::new(1., 1., 1.));
+        let expected = Patterns::new_cube_map(
+            corners.clone(),
+            squares.clone(),
+            corners.clone(),
+            squares.clone(),
+            corners,
+            squares,
+        );
+        assert_eq!(scene.world.objects[0].get_shape().material.pattern, Some(expected));
+    }
+
+    #[test]
+    fn read_image_texture() {
+        let mut image = Canvas::new(2, 1);
+        image.fill(Color::new(1., 0., 0.));
+        let path = std::env::temp_dir().join("scene_tests_texture.ppm");
+        image.save(path.to_str().unwrap(), BitDepth::Eight).unwrap();
+
+        let scene = with_camera(&format!("- add: cube
+  material:
+    pattern:
+      type: map
+      mapping: cube
+      uv-pattern:
+        type: image
+        file: {}
+        filtering: nearest
+", path.to_str().unwrap())).unwrap();
+
+        let expected = Patterns::new_texture_map(UvPattern::new_image(image, Filtering::Nearest), UvMapping::Cube);
+        assert_eq!(scene.world.objects[0].get_shape().material.pattern, Some(expected));
+
+        let missing = with_camera("- add: cube
+  material:
+    pattern:
+      type: map
+      mapping: cube
+      uv-pattern:
+        type: image
+        file: does_not_exist.png
+");
+        assert!(missing.err().unwrap().starts_with("line 16: could not read"));
+    }
+
+    #[test]
+    fn obj_meshes_are_divided() {
+        // A row of triangles, far enough apart to be split up
+        let mut obj = String::new();
+        for i in 0..16 {
+            let x = i as f64 * 2.;
+            obj += &format!("v {} 0 0\nv {} 0 0\nv {} 1 0\n", x, x + 1., x);
+        }
+        for i in 0..16 {
+            obj += &format!("f {} {} {}\n", i * 3 + 1, i * 3 + 2, i * 3 + 3);
+        }
+        let path = std::env::temp_dir().join("scene_tests_mesh.obj");
+        std::fs::write(&path, obj).unwrap();
+
+        let scene = with_camera(&format!("- add: obj
+  file: {}
+", path.to_str().unwrap())).unwrap();
+
+        match &scene.world.objects[0] {
+            Drawables::Group(mesh) => {
+                assert!(mesh.children().len() < 16);
+                assert!(mesh.children().iter().all(|c| matches!(c, Drawables::Group(_))));
+            },
+            _ => panic!("expected a group"),
+        }
+    }
+
+    #[test]
+    fn read_pattern() {
+        let scene = with_camera("- add: plane
+  material:
+    pattern:
+      type: checkers
+      colors:
+        - [1, 1, 1]
+        - [0, 0, 0]
+      transform:
+        - [scale, 0.5, 0.5, 0.5]
+").unwrap();
+
+        let mut expected = Patterns::new_checker(Color::new(1., 1., 1.), Color::new(0., 0., 0.));
+        expected.set_transform(Matrix4::new_scaling(0.5, 0.5, 0.5));
+        assert_eq!(scene.world.objects[0].get_shape().material.pattern, Some(expected));
+    }
+
+    #[test]
+    fn define_and_extend() {
+        let scene = with_camera("- define: white
+  value:
+    color: [1, 1, 1]
+    diffuse: 0.7
+- define: blue
+  extend: white
+  value:
+    color: [0, 0, 1]
+- define: standard
+  value:
+    - [translate, 1, 0, 0]
+- define: big-sphere
+  value:
+    add: sphere
+    material: blue
+    transform:
+      - standard
+      - [scale, 2, 2, 2]
+- add: big-sphere
+- add: big-sphere
+  material: white
+").unwrap();
+
+        let objects = &scene.world.objects;
+        assert_eq!(objects[0].get_shape().material.color, Color::new(0., 0., 1.));
+        assert_eq!(objects[0].get_shape().material.diffuse, 0.7);
+        assert_eq!(objects[0].get_transform(), Matrix4::new_scaling(2., 2., 2.) * Matrix4::new_translation(1., 0., 0.));
+        assert_eq!(objects[1].get_shape().material.color, Color::new(1., 1., 1.));
+    }
+
+    #[test]
+    fn read_scene_file() {
+        let scene = Scene::from_file("scenes/sphere_world.yml").unwrap();
+
+        assert_eq!(scene.camera.h_size, 800);
+        assert_eq!(scene.world.lights.len(), 2);
+        assert_eq!(scene.world.objects.len(), 6);
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let cases = [
+            ("- add: sphere\n  colour: [1, 0, 0]", "line 10: unknown key 'colour'"),
+            ("- add: sphere\n  material:\n    diffuse: lots", "line 11: expected a number"),
+            ("- add: sphear", "line 9: unknown object type 'sphear'"),
+            ("- add: sphere\n  material: shiny", "line 10: unknown define 'shiny'"),
+            ("- add: sphere\n  transform:\n    - [translate, 1, 2]", "line 11: translate takes 3 values"),
+            ("- add: light\n  intensity: [1, 1, 1]", "line 9: missing key 'at'"),
+            ("- add: light\n  corner: [0, 0, 0]\n  at: [0, 0, 0]", "line 11: unknown key 'at'"),
+            ("- add: cylinder\n  closed: yes", "line 10: expected true or false"),
+            ("- add: plane\n  material:\n    color: [1, 1]", "line 11: expected a list of three numbers"),
+        ];
+
+        for (input, message) in cases.iter() {
+            assert_eq!(with_camera(input).err(), Some(message.to_string()));
+        }
+    }
+
+    #[test]
+    fn define_that_refers_to_itself() {
+        let stripes = "type: stripes\n    colors:\n      - [1, 1, 1]\n      - [0, 0, 0]";
+        let cases = [
+            ("- define: t\n  value: [t]\n- add: sphere\n  transform: t".to_string(),
+                "line 10: define 't' refers to itself"),
+            ("- define: a\n  value: [b]\n- define: b\n  value: [a]\n- add: sphere\n  transform: a".to_string(),
+                "line 12: define 'a' refers to itself"),
+            ("- define: p\n  value:\n    type: perturbed\n    pattern: p\n- add: plane\n  material:\n    pattern: p".to_string(),
+                "line 12: define 'p' refers to itself"),
+            (format!("- define: p\n  value:\n    type: blend\n    patterns:\n      - p\n      - {}\n- add: plane\n  material:\n    pattern: p", stripes.replace("\n    ", "\n        ")),
+                "line 13: define 'p' refers to itself"),
+            ("- define: p\n  value:\n    type: checkers\n    colors:\n      - p\n      - [0, 0, 0]\n- add: plane\n  material:\n    pattern: p".to_string(),
+                "line 13: define 'p' refers to itself"),
+        ];
+
+        for (input, message) in cases.iter() {
+            assert_eq!(with_camera(input).err(), Some(message.to_string()));
+        }
+
+        // Using the same define twice side by side is fine
+        let scene = with_camera(&format!("- define: s\n  value:\n    {}\n- add: plane\n  material:\n    pattern:\n      type: blend\n      patterns: [s, s]", stripes));
+        assert!(scene.is_ok(), "{:?}", scene.err());
+    }
+
+    #[test]
+    fn scene_needs_a_camera() {
+        assert_eq!(Scene::parse("- add: sphere").err(), Some(String::from("the scene has no camera")));
+    }
+}
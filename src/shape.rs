@@ -2,38 +2,93 @@ use super::material::Material;
 use super::matrix::Matrix4;
 use super::tuple::Tuple;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+// The transforms are private so that their cached inverses can't go stale.
+// Inverting a matrix is slow, and we would otherwise do it for every ray.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Shape {
     pub origin: Tuple,
-    pub transform: Matrix4,
+    transform: Matrix4,
+    transform_inverse: Option<Matrix4>,
+    transform_inverse_transpose: Option<Matrix4>,
     pub material: Material,
+    // The combined transform of every group this shape is nested in,
+    // kept up to date by Group
+    parent_transform: Matrix4,
+    parent_inverse: Option<Matrix4>,
+    parent_inverse_transpose: Option<Matrix4>,
 }
 
+#[allow(dead_code)]
 impl Shape {
      pub fn new_with_transform(transform: Matrix4) -> Self {
-        Self {
-            transform,
-            ..Default::default()
-        }
+        let mut shape: Self = Default::default();
+        shape.set_transform(transform);
+        shape
+    }
+
+    pub fn get_transform(&self) -> Matrix4 {
+        self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.transform_inverse = transform.inverse();
+        self.transform_inverse_transpose = self.transform_inverse.map(|m| m.transpose());
+    }
+
+    // None if the transform can't be inverted
+    pub fn get_inverse_transform(&self) -> Option<Matrix4> {
+        self.transform_inverse
     }
 
-    pub fn get_object_point(self, world_point: Tuple) -> Option<Tuple> {
-        Some(self.transform.inverse()? * world_point)
+    pub fn get_parent_transform(&self) -> Matrix4 {
+        self.parent_transform
     }
 
-    pub fn get_world_normal(self, object_normal: Tuple) -> Option<Tuple> {
-        Some(self.transform.inverse()?.transpose() * object_normal)
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.parent_transform = parent_transform;
+        self.parent_inverse = parent_transform.inverse();
+        self.parent_inverse_transpose = self.parent_inverse.map(|m| m.transpose());
     }
 
+    // Converts a point all the way from world space, through any parent groups,
+    // into the space of this shape
+    pub fn world_to_object(&self, world_point: Tuple) -> Option<Tuple> {
+        let parent_point = self.parent_inverse? * world_point;
+        Some(self.transform_inverse? * parent_point)
+    }
+
+    // Converts a normal from the space of this shape, through any parent groups,
+    // back out to world space
+    pub fn normal_to_world(&self, object_normal: Tuple) -> Option<Tuple> {
+        let mut normal = self.transform_inverse_transpose? * object_normal;
+        normal.w = 0.0;
+        normal = normal.normalize();
+
+        let mut world_normal = self.parent_inverse_transpose? * normal;
+        world_normal.w = 0.0;
+
+        Some(world_normal.normalize())
+    }
+
+    pub fn get_world_transform(&self) -> Matrix4 {
+        self.parent_transform * self.transform
+    }
 }
 
 impl Default for Shape {
     fn default() -> Self {
         let material: Material = Default::default();
+        let identity = Matrix4::new_identity();
         Self {
             origin: Tuple::new_point(0., 0., 0.),
-            transform: Matrix4::new_identity(),
+            transform: identity,
+            transform_inverse: Some(identity),
+            transform_inverse_transpose: Some(identity),
             material,
+            parent_transform: identity,
+            parent_inverse: Some(identity),
+            parent_inverse_transpose: Some(identity),
         }
     }
 }
@@ -1,9 +1,42 @@
 #[cfg(test)]
 
 mod shape_tests {
+    use crate::shape::Shape;
+    use crate::matrix::Matrix4;
+    use crate::tuple::Tuple;
 
     #[test]
     fn shape_has_default_transformation() {
+        let s: Shape = Default::default();
+        assert_eq!(s.get_transform(), Matrix4::new_identity());
+        assert_eq!(s.get_inverse_transform(), Some(Matrix4::new_identity()));
+    }
+
+    #[test]
+    fn setting_transform_updates_inverse() {
+        let mut s: Shape = Default::default();
+        let t = Matrix4::new_translation(2., 3., 4.);
+        s.set_transform(t);
+
+        assert_eq!(s.get_transform(), t);
+        assert_eq!(s.get_inverse_transform(), t.inverse());
+        assert_eq!(s.world_to_object(Tuple::new_point(2., 3., 4.)), Some(Tuple::new_point(0., 0., 0.)));
+    }
+
+    #[test]
+    fn setting_parent_transform_updates_inverse() {
+        let mut s = Shape::new_with_transform(Matrix4::new_scaling(2., 2., 2.));
+        s.set_parent_transform(Matrix4::new_translation(0., 0., 5.));
+
+        assert_eq!(s.world_to_object(Tuple::new_point(2., 0., 5.)), Some(Tuple::new_point(1., 0., 0.)));
+        assert_eq!(s.normal_to_world(Tuple::new_vector(0., 1., 0.)), Some(Tuple::new_vector(0., 1., 0.)));
+    }
+
+    #[test]
+    fn non_invertible_transform() {
+        let s = Shape::new_with_transform(Matrix4::new_scaling(0., 1., 1.));
 
+        assert_eq!(s.get_inverse_transform(), None);
+        assert_eq!(s.world_to_object(Tuple::new_point(1., 1., 1.)), None);
     }
 }
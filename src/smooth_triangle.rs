@@ -0,0 +1,98 @@
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::Drawable;
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::triangle::intersect_uv;
+use super::bounds::BoundingBox;
+
+// A triangle that interpolates the normals given for each of its
+// vertices, so that a mesh of them looks rounded instead of faceted
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothTriangle {
+    pub shape: Shape,
+    pub p1: Tuple,
+    pub p2: Tuple,
+    pub p3: Tuple,
+    pub n1: Tuple,
+    pub n2: Tuple,
+    pub n3: Tuple,
+    pub e1: Tuple,
+    pub e2: Tuple,
+}
+
+#[allow(dead_code)]
+impl SmoothTriangle {
+    pub fn new(p1: Tuple, p2: Tuple, p3: Tuple, n1: Tuple, n2: Tuple, n3: Tuple) -> Self {
+        let shape: Shape = Default::default();
+
+        Self {
+            shape,
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+        }
+    }
+
+    pub fn local_intersect_uv(&self, local_ray: Ray) -> Option<(f64, f64, f64)> {
+        intersect_uv(self.p1, self.e1, self.e2, local_ray)
+    }
+
+    // Finds the barycentric coordinates of a point on the triangle,
+    // for when the hit doesn't already know them
+    fn barycentric(&self, local_point: Tuple) -> (f64, f64) {
+        let p1_to_point = local_point - self.p1;
+        let d00 = self.e1.dot(self.e1);
+        let d01 = self.e1.dot(self.e2);
+        let d11 = self.e2.dot(self.e2);
+        let d20 = p1_to_point.dot(self.e1);
+        let d21 = p1_to_point.dot(self.e2);
+        let denominator = d00 * d11 - d01 * d01;
+
+        let u = (d11 * d20 - d01 * d21) / denominator;
+        let v = (d00 * d21 - d01 * d20) / denominator;
+        (u, v)
+    }
+}
+
+impl Drawable for SmoothTriangle {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let (t, _, _) = self.local_intersect_uv(local_ray)?;
+        Some(vec![t])
+    }
+
+    fn local_normal_at(&self, local_point: Tuple, hit: &Intersection) -> Tuple {
+        let (u, v) = match hit.uv {
+            Some(uv) => uv,
+            None => self.barycentric(local_point),
+        };
+
+        self.n2 * u + self.n3 * v + self.n1 * (1. - u - v)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+        bounds
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+
+mod smooth_triangle_tests {
+    use crate::smooth_triangle::*;
+    use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
+    use crate::ray::Ray;
+    use crate::generics::{Drawable, Drawables};
+    use crate::utils::is_equal;
+
+    fn default_smooth_triangle() -> SmoothTriangle {
+        SmoothTriangle::new(
+            Tuple::new_point(0., 1., 0.),
+            Tuple::new_point(-1., 0., 0.),
+            Tuple::new_point(1., 0., 0.),
+            Tuple::new_vector(0., 1., 0.),
+            Tuple::new_vector(-1., 0., 0.),
+            Tuple::new_vector(1., 0., 0.),
+        )
+    }
+
+    #[test]
+    fn construct_smooth_triangle() {
+        let t = default_smooth_triangle();
+
+        assert_eq!(t.p1, Tuple::new_point(0., 1., 0.));
+        assert_eq!(t.p2, Tuple::new_point(-1., 0., 0.));
+        assert_eq!(t.p3, Tuple::new_point(1., 0., 0.));
+        assert_eq!(t.n1, Tuple::new_vector(0., 1., 0.));
+        assert_eq!(t.n2, Tuple::new_vector(-1., 0., 0.));
+        assert_eq!(t.n3, Tuple::new_vector(1., 0., 0.));
+    }
+
+    #[test]
+    fn intersection_encapsulates_u_and_v() {
+        let d = Drawables::SmoothTriangle(default_smooth_triangle());
+        let i = Intersection::new_with_uv(3.5, &d, 0.2, 0.4);
+
+        assert_eq!(i.uv, Some((0.2, 0.4)));
+    }
+
+    #[test]
+    fn intersection_stores_u_and_v() {
+        let d = Drawables::SmoothTriangle(default_smooth_triangle());
+        let r = Ray::new(Tuple::new_point(-0.2, 0.3, -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = r.intersect(&d).unwrap();
+        let (u, v) = xs[0].uv.unwrap();
+
+        assert_eq!(xs.len(), 1);
+        assert!(is_equal(u, 0.45));
+        assert!(is_equal(v, 0.25));
+    }
+
+    #[test]
+    fn interpolate_normal() {
+        let d = Drawables::SmoothTriangle(default_smooth_triangle());
+        let i = Intersection::new_with_uv(1., &d, 0.45, 0.25);
+        let n = d.normal_at(Tuple::new_point(0., 0., 0.), &i);
+
+        assert_eq!(n, Some(Tuple::new_vector(-0.5547, 0.83205, 0.)));
+    }
+
+    #[test]
+    fn interpolate_normal_without_uv() {
+        let t = default_smooth_triangle();
+        let d = Drawables::SmoothTriangle(t.clone());
+        let i = Intersection::new(1., &d);
+        let n = t.local_normal_at(Tuple::new_point(-0.2, 0.3, 0.), &i);
+
+        assert_eq!(n.normalize(), Tuple::new_vector(-0.5547, 0.83205, 0.));
+    }
+
+    #[test]
+    fn prepare_normal_on_smooth_triangle() {
+        let d = Drawables::SmoothTriangle(default_smooth_triangle());
+        let i = Intersection::new_with_uv(1., &d, 0.45, 0.25);
+        let r = Ray::new(Tuple::new_point(-0.2, 0.3, -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = vec![i];
+        let comps = i.prepare_computations(r, Some(&xs)).unwrap();
+
+        assert_eq!(comps.normal_v, Tuple::new_vector(-0.5547, 0.83205, 0.));
+    }
+}
@@ -3,8 +3,10 @@ use super::tuple::Tuple;
 use super::shape::Shape;
 use super::generics::Drawable;
 use super::ray::Ray;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Sphere {
     pub shape: Shape
 }
@@ -26,7 +28,7 @@ impl Sphere {
 }
 
 impl Drawable for Sphere {
-    fn local_intersect(&self, local_ray: Ray) -> Option<[Option<f64>; 2]> {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
         let sphere_to_ray = local_ray.origin - self.shape.origin;
         let a = local_ray.direction.dot(local_ray.direction);
         let b = 2.0 * local_ray.direction.dot(sphere_to_ray);
@@ -41,21 +43,32 @@ impl Drawable for Sphere {
         let t1 = (-b - discriminant.sqrt()) / (2.0 * a);
         let t2 = (-b + discriminant.sqrt()) / (2.0 * a);
 
-        Some([
-            Some(t1),
-            Some(t2)
-        ])
+        Some(vec![t1, t2])
     }
 
-    fn local_normal_at(&self, local_point: Tuple) -> Tuple {
+    fn local_normal_at(&self, local_point: Tuple, _: &Intersection) -> Tuple {
         local_point - Tuple::new_point(0., 0., 0.)
     }
 
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Tuple::new_point(-1., -1., -1.), Tuple::new_point(1., 1., 1.))
+    }
+
     fn get_transform(&self) -> Matrix4 {
-        self.shape.transform
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
     }
 
-    fn get_shape(&self) -> Shape {
-        self.shape
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -4,62 +4,74 @@ mod sphere_tests {
     use crate::sphere::*;
     use crate::matrix::*;
     use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
     use crate::material::Material;
     use crate::generics::{Drawable, Drawables};
 
     #[test]
     fn sphere_default_transform() {
         let s = Sphere::new();
-        assert_eq!(s.shape.transform, Matrix4::new_identity());
+        assert_eq!(s.shape.get_transform(), Matrix4::new_identity());
     }
 
     #[test]
     fn sphere_change_transform() {
         let mut s = Sphere::new();
         let t = Matrix4::new_translation(2., 3., 4.);
-        s.shape.transform = t;
-        assert_eq!(s.shape.transform, t);
+        s.shape.set_transform(t);
+        assert_eq!(s.shape.get_transform(), t);
     }
 
     #[test]
     fn normal_point_x_axis() {
         let s = Sphere::new();
-        let n = s.local_normal_at(Tuple::new_point(1., 0., 0.));
+        let d = Drawables::Sphere(s.clone());
+        let i = Intersection::new(0., &d);
+        let n = s.local_normal_at(Tuple::new_point(1., 0., 0.), &i);
         assert_eq!(n, Tuple::new_vector(1., 0., 0.));
     }
 
     #[test]
     fn normal_point_y_axis() {
         let s = Sphere::new();
-        let n = s.local_normal_at(Tuple::new_point(0., 1., 0.));
+        let d = Drawables::Sphere(s.clone());
+        let i = Intersection::new(0., &d);
+        let n = s.local_normal_at(Tuple::new_point(0., 1., 0.), &i);
         assert_eq!(n, Tuple::new_vector(0., 1., 0.));
     }
 
     #[test]
     fn normal_point_z_axis() {
         let s = Sphere::new();
-        let n = s.local_normal_at(Tuple::new_point(0., 0., 1.));
+        let d = Drawables::Sphere(s.clone());
+        let i = Intersection::new(0., &d);
+        let n = s.local_normal_at(Tuple::new_point(0., 0., 1.), &i);
         assert_eq!(n, Tuple::new_vector(0., 0., 1.));
     }
 
     #[test]
     fn normal_point_non_axial() {
         let s = Sphere::new();
-        let n = s.local_normal_at(Tuple::new_point(3_f64.sqrt() / 3., 3_f64.sqrt() / 3., 3_f64.sqrt() / 3.));
+        let d = Drawables::Sphere(s.clone());
+        let i = Intersection::new(0., &d);
+        let n = s.local_normal_at(Tuple::new_point(3_f64.sqrt() / 3., 3_f64.sqrt() / 3., 3_f64.sqrt() / 3.), &i);
         assert_eq!(n, Tuple::new_vector(3_f64.sqrt() / 3., 3_f64.sqrt() / 3., 3_f64.sqrt() / 3.));
     }
 
     #[test]
     fn normal_point_normalized() {
         let s = Sphere::new();
-        let n = s.local_normal_at(Tuple::new_point(3_f64.sqrt() / 3., 3_f64.sqrt() / 3., 3_f64.sqrt() / 3.));
+        let d = Drawables::Sphere(s.clone());
+        let i = Intersection::new(0., &d);
+        let n = s.local_normal_at(Tuple::new_point(3_f64.sqrt() / 3., 3_f64.sqrt() / 3., 3_f64.sqrt() / 3.), &i);
         assert_eq!(n, n.normalize());
     }
 
     #[test]
     fn world_normal_translated_sphere() {
         let s = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(0., 1., 0.)));
-        let n = s.normal_at(Tuple::new_point(0., 1.70711, -0.70711));
+        let i = Intersection::new(0., &s);
+        let n = s.normal_at(Tuple::new_point(0., 1.70711, -0.70711), &i);
         assert_eq!(n, Some(Tuple::new_vector(0., 0.70711, -0.70711)));
     }
 
@@ -68,7 +80,8 @@ mod sphere_tests {
         use std::f64::consts::PI;
         let m = Matrix4::new_scaling(1., 0.5, 1.) * Matrix4::new_rotation_z(PI / 5.);
         let s = Drawables::Sphere(Sphere::new_with_transform(m));
-        let n = s.normal_at(Tuple::new_point(0., 2_f64.sqrt() / 2., -2_f64.sqrt() / 2.));
+        let i = Intersection::new(0., &s);
+        let n = s.normal_at(Tuple::new_point(0., 2_f64.sqrt() / 2., -2_f64.sqrt() / 2.), &i);
         assert_eq!(n, Some(Tuple::new_vector(0., 0.97014, -0.24254)));
     }
 
@@ -84,7 +97,7 @@ mod sphere_tests {
         let mut s = Sphere::new();
         let mut m: Material = Default::default();
         m.ambient = 1.0;
-        s.shape.material = m;
+        s.shape.material = m.clone();
         assert_eq!(s.shape.material, m);
     }
 }
@@ -0,0 +1,68 @@
+use super::tuple::Tuple;
+use super::color::Color;
+use super::utils::EPSILON;
+
+// A point light that only shines in a cone around its direction. Inside of
+// the inner angle it is at full strength, and between the inner and the outer
+// angle it fades out smoothly. Both angles are measured from the direction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpotLight {
+    pub position: Tuple,
+    pub direction: Tuple,
+    pub inner_angle: f64,
+    pub outer_angle: f64,
+    pub intensity: Color,
+}
+
+#[allow(dead_code)]
+impl SpotLight {
+    pub fn new(
+        position: Tuple,
+        direction: Tuple,
+        inner_angle: f64,
+        outer_angle: f64,
+        intensity: Color,
+    ) -> Result<Self, &'static str> {
+        if position.is_vector() {
+            return Err("Position can't be a vector");
+        }
+        if direction.is_point() || direction.magnitude() == 0. {
+            return Err("Direction has to be a vector with a length");
+        }
+        if inner_angle < 0. || inner_angle > outer_angle {
+            return Err("The inner angle has to be between 0 and the outer angle");
+        }
+
+        Ok(Self {
+            position,
+            direction: direction.normalize(),
+            inner_angle,
+            outer_angle,
+            intensity,
+        })
+    }
+
+    // How much of the light reaches the point, from 0 outside of
+    // the cone to 1 inside of the inner angle
+    pub fn falloff(&self, point: Tuple) -> f64 {
+        // Right at the light there is no direction to compare with
+        let to_point = point - self.position;
+        if to_point.magnitude() < EPSILON {
+            return 1.;
+        }
+
+        let cos = to_point.normalize().dot(self.direction);
+        let cos_inner = self.inner_angle.cos();
+        let cos_outer = self.outer_angle.cos();
+
+        if cos >= cos_inner {
+            1.
+        } else if cos <= cos_outer {
+            0.
+        } else {
+            // Smoothstep, so there is no visible edge where the fade starts
+            let t = (cos - cos_outer) / (cos_inner - cos_outer);
+            t * t * (3. - 2. * t)
+        }
+    }
+}
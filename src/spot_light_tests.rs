@@ -0,0 +1,91 @@
+#[cfg(test)]
+
+mod spot_light_tests {
+    use crate::spot_light::*;
+    use crate::color::Color;
+    use crate::tuple::Tuple;
+    use crate::utils::is_equal;
+
+    use std::f64::consts::PI;
+
+    fn light() -> SpotLight {
+        SpotLight::new(
+            Tuple::new_point(0., 10., 0.),
+            Tuple::new_vector(0., -2., 0.),
+            PI / 8.,
+            PI / 4.,
+            Color::new(1., 1., 1.),
+        ).unwrap()
+    }
+
+    #[test]
+    fn create_spot_light() {
+        let light = light();
+
+        assert_eq!(light.position, Tuple::new_point(0., 10., 0.));
+        assert_eq!(light.direction, Tuple::new_vector(0., -1., 0.));
+        assert_eq!((light.inner_angle, light.outer_angle), (PI / 8., PI / 4.));
+    }
+
+    #[test]
+    fn bad_spot_lights() {
+        let position = Tuple::new_point(0., 0., 0.);
+        let direction = Tuple::new_vector(0., -1., 0.);
+        let white = Color::new(1., 1., 1.);
+
+        assert!(SpotLight::new(direction, direction, 0.1, 0.2, white).is_err());
+        assert!(SpotLight::new(position, Tuple::new_vector(0., 0., 0.), 0.1, 0.2, white).is_err());
+        assert!(SpotLight::new(position, direction, 0.3, 0.2, white).is_err());
+        assert!(SpotLight::new(position, direction, -0.1, 0.2, white).is_err());
+    }
+
+    #[test]
+    fn falloff_inside_the_inner_cone() {
+        let light = light();
+
+        assert_eq!(light.falloff(Tuple::new_point(0., 0., 0.)), 1.);
+        assert_eq!(light.falloff(Tuple::new_point(2., 0., 2.)), 1.);
+    }
+
+    #[test]
+    fn falloff_outside_the_outer_cone() {
+        let light = light();
+
+        assert_eq!(light.falloff(Tuple::new_point(10.1, 0., 0.)), 0.);
+        assert_eq!(light.falloff(Tuple::new_point(0., 20., 0.)), 0.);
+    }
+
+    #[test]
+    fn falloff_fades_between_the_cones() {
+        let light = light();
+        let at_angle = |angle: f64| light.falloff(Tuple::new_point(10. * angle.tan(), 0., 0.));
+
+        let middle = (PI / 8. + PI / 4.) / 2.;
+        assert!(is_equal(at_angle(PI / 8. - 0.001), 1.));
+        assert!(is_equal(at_angle(PI / 4. + 0.001), 0.));
+        assert!(at_angle(middle) > 0. && at_angle(middle) < 1.);
+        assert!(at_angle(middle - 0.1) > at_angle(middle));
+        assert!(at_angle(middle + 0.1) < at_angle(middle));
+    }
+
+    #[test]
+    fn hard_edged_spot_light() {
+        let light = SpotLight::new(
+            Tuple::new_point(0., 10., 0.),
+            Tuple::new_vector(0., -1., 0.),
+            PI / 4.,
+            PI / 4.,
+            Color::new(1., 1., 1.),
+        ).unwrap();
+
+        assert_eq!(light.falloff(Tuple::new_point(9.9, 0., 0.)), 1.);
+        assert_eq!(light.falloff(Tuple::new_point(10.1, 0., 0.)), 0.);
+    }
+
+    #[test]
+    fn falloff_at_the_light() {
+        let light = light();
+
+        assert_eq!(light.falloff(light.position), 1.);
+    }
+}
@@ -0,0 +1,188 @@
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use super::tuple::Tuple;
+use super::color::Color;
+use super::canvas::Canvas;
+
+// Ways to flatten the surface of a shape, turning a point on it into
+// u and v between 0 and 1 where a 2D pattern or image can be looked up
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UvMapping {
+    // Wraps around a unit sphere like lines of longitude and latitude
+    Spherical,
+    // Repeats every unit along x and z
+    Planar,
+    // Wraps around a unit cylinder, repeating every unit up the side
+    Cylindrical,
+    // Puts the whole image on every face of a unit cube
+    Cube,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CubeFace {
+    Left,
+    Right,
+    Front,
+    Back,
+    Up,
+    Down,
+}
+
+// How an image is read between the centres of its pixels
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Filtering {
+    // The closest pixel, which looks blocky up close
+    Nearest,
+    // A blend of the four closest pixels
+    Bilinear,
+}
+
+// Patterns that live on a flat surface, see UvMapping
+#[derive(Debug, Clone, PartialEq)]
+pub enum UvPattern {
+    // width by height squares over the whole surface
+    Checkers { width: f64, height: f64, a: Color, b: Color },
+    // Shared, since the same image tends to be used by many objects
+    Image { image: Arc<Canvas>, filtering: Filtering },
+    // A different color in each corner, upper left, upper right, bottom left
+    // and bottom right, to see which way up a face is
+    AlignCheck { main: Color, ul: Color, ur: Color, bl: Color, br: Color },
+}
+
+#[allow(dead_code)]
+impl UvMapping {
+    pub fn map(self, point: Tuple) -> (f64, f64) {
+        match self {
+            UvMapping::Spherical => spherical_map(point),
+            UvMapping::Planar => planar_map(point),
+            UvMapping::Cylindrical => cylindrical_map(point),
+            UvMapping::Cube => cube_uv(face_from_point(point), point),
+        }
+    }
+}
+
+pub fn spherical_map(point: Tuple) -> (f64, f64) {
+    let theta = point.x.atan2(point.z);
+    let radius = Tuple::new_vector(point.x, point.y, point.z).magnitude();
+    let phi = (point.y / radius).acos();
+
+    // theta goes from -pi to pi, and u has to grow counter clockwise
+    // when looking down at the sphere
+    let raw_u = theta / (2. * PI);
+    let u = 1. - (raw_u + 0.5);
+    // Flipped so that v is 1 at the north pole
+    let v = 1. - phi / PI;
+
+    (u, v)
+}
+
+pub fn planar_map(point: Tuple) -> (f64, f64) {
+    (point.x.rem_euclid(1.), point.z.rem_euclid(1.))
+}
+
+pub fn cylindrical_map(point: Tuple) -> (f64, f64) {
+    let theta = point.x.atan2(point.z);
+    let raw_u = theta / (2. * PI);
+    let u = 1. - (raw_u + 0.5);
+
+    (u, point.y.rem_euclid(1.))
+}
+
+// The face of a unit cube the point is on, picked by its largest coordinate
+pub fn face_from_point(point: Tuple) -> CubeFace {
+    let coord = point.x.abs().max(point.y.abs()).max(point.z.abs());
+
+    if coord == point.x {
+        CubeFace::Right
+    } else if coord == -point.x {
+        CubeFace::Left
+    } else if coord == point.y {
+        CubeFace::Up
+    } else if coord == -point.y {
+        CubeFace::Down
+    } else if coord == point.z {
+        CubeFace::Front
+    } else {
+        CubeFace::Back
+    }
+}
+
+// Where on the face the point is, as if looking straight at the face
+// from outside of the cube
+pub fn cube_uv(face: CubeFace, point: Tuple) -> (f64, f64) {
+    let (u, v) = match face {
+        CubeFace::Front => (point.x + 1., point.y + 1.),
+        CubeFace::Back => (1. - point.x, point.y + 1.),
+        CubeFace::Left => (point.z + 1., point.y + 1.),
+        CubeFace::Right => (1. - point.z, point.y + 1.),
+        CubeFace::Up => (point.x + 1., 1. - point.z),
+        CubeFace::Down => (point.x + 1., point.z + 1.),
+    };
+
+    (u.rem_euclid(2.) / 2., v.rem_euclid(2.) / 2.)
+}
+
+#[allow(dead_code)]
+impl UvPattern {
+    pub fn new_checkers(width: f64, height: f64, a: Color, b: Color) -> Self {
+        UvPattern::Checkers { width, height, a, b }
+    }
+
+    pub fn new_image(image: Canvas, filtering: Filtering) -> Self {
+        UvPattern::Image { image: Arc::new(image), filtering }
+    }
+
+    pub fn new_align_check(main: Color, ul: Color, ur: Color, bl: Color, br: Color) -> Self {
+        UvPattern::AlignCheck { main, ul, ur, bl, br }
+    }
+
+    pub fn color_at(&self, u: f64, v: f64) -> Color {
+        match self {
+            UvPattern::Checkers { width, height, a, b } => {
+                let u = (u * width).floor();
+                let v = (v * height).floor();
+                if (u + v).rem_euclid(2.) == 0. {
+                    *a
+                } else {
+                    *b
+                }
+            },
+            UvPattern::Image { image, filtering } => image_color_at(image, *filtering, u, v),
+            UvPattern::AlignCheck { main, ul, ur, bl, br } => {
+                match (u < 0.2, u > 0.8, v < 0.2, v > 0.8) {
+                    (true, _, _, true) => *ul,
+                    (_, true, _, true) => *ur,
+                    (true, _, true, _) => *bl,
+                    (_, true, true, _) => *br,
+                    _ => *main,
+                }
+            },
+        }
+    }
+}
+
+// v goes up but the rows of an image go down, so v is flipped
+fn image_color_at(image: &Canvas, filtering: Filtering, u: f64, v: f64) -> Color {
+    let height = image.data.len();
+    let width = image.data.first().map_or(0, |row| row.len());
+    if width == 0 {
+        return Color::new(0., 0., 0.);
+    }
+
+    let x = u.clamp(0., 1.) * (width - 1) as f64;
+    let y = (1. - v.clamp(0., 1.)) * (height - 1) as f64;
+
+    match filtering {
+        Filtering::Nearest => image.data[y.round() as usize][x.round() as usize],
+        Filtering::Bilinear => {
+            let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+            let (x1, y1) = ((x0 + 1).min(width - 1), (y0 + 1).min(height - 1));
+            let (fx, fy) = (x - x0 as f64, y - y0 as f64);
+
+            let top = image.data[y0][x0] * (1. - fx) + image.data[y0][x1] * fx;
+            let bottom = image.data[y1][x0] * (1. - fx) + image.data[y1][x1] * fx;
+            top * (1. - fy) + bottom * fy
+        },
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(test)]
+
+mod texture_tests {
+    use crate::texture::*;
+    use crate::tuple::Tuple;
+    use crate::color::Color;
+    use crate::canvas::Canvas;
+    use crate::utils::is_equal;
+
+    const BLACK: Color = Color { r: 0., g: 0., b: 0. };
+    const WHITE: Color = Color { r: 1., g: 1., b: 1. };
+
+    fn assert_uv(actual: (f64, f64), expected: (f64, f64)) {
+        assert!(is_equal(actual.0, expected.0) && is_equal(actual.1, expected.1),
+            "expected {:?}, got {:?}", expected, actual);
+    }
+
+    #[test]
+    fn checkers_in_2d() {
+        let checkers = UvPattern::new_checkers(2., 2., BLACK, WHITE);
+        let cases = [
+            (0., 0., BLACK),
+            (0.5, 0., WHITE),
+            (0., 0.5, WHITE),
+            (0.5, 0.5, BLACK),
+            (1., 1., BLACK),
+        ];
+
+        for (u, v, color) in cases.iter() {
+            assert_eq!(checkers.color_at(*u, *v), *color);
+        }
+    }
+
+    #[test]
+    fn spherical_mapping() {
+        let cases = [
+            (Tuple::new_point(0., 0., -1.), (0., 0.5)),
+            (Tuple::new_point(1., 0., 0.), (0.25, 0.5)),
+            (Tuple::new_point(0., 0., 1.), (0.5, 0.5)),
+            (Tuple::new_point(-1., 0., 0.), (0.75, 0.5)),
+            (Tuple::new_point(0., 1., 0.), (0.5, 1.)),
+            (Tuple::new_point(0., -1., 0.), (0.5, 0.)),
+            (Tuple::new_point(2_f64.sqrt() / 2., 2_f64.sqrt() / 2., 0.), (0.25, 0.75)),
+        ];
+
+        for (point, uv) in cases.iter() {
+            assert_uv(spherical_map(*point), *uv);
+            assert_uv(UvMapping::Spherical.map(*point), *uv);
+        }
+    }
+
+    #[test]
+    fn planar_mapping() {
+        let cases = [
+            (Tuple::new_point(0.25, 0., 0.5), (0.25, 0.5)),
+            (Tuple::new_point(0.25, 0., -0.25), (0.25, 0.75)),
+            (Tuple::new_point(0.25, 0.5, -0.25), (0.25, 0.75)),
+            (Tuple::new_point(1.25, 0., 0.5), (0.25, 0.5)),
+            (Tuple::new_point(0.25, 0., -1.75), (0.25, 0.25)),
+            (Tuple::new_point(1., 0., -1.), (0., 0.)),
+            (Tuple::new_point(0., 0., 0.), (0., 0.)),
+        ];
+
+        for (point, uv) in cases.iter() {
+            assert_uv(planar_map(*point), *uv);
+            assert_uv(UvMapping::Planar.map(*point), *uv);
+        }
+    }
+
+    #[test]
+    fn cylindrical_mapping() {
+        let d = 2_f64.sqrt() / 2.;
+        let cases = [
+            (Tuple::new_point(0., 0., -1.), (0., 0.)),
+            (Tuple::new_point(0., 0.5, -1.), (0., 0.5)),
+            (Tuple::new_point(0., 1., -1.), (0., 0.)),
+            (Tuple::new_point(d, 0.5, -d), (0.125, 0.5)),
+            (Tuple::new_point(1., 0.5, 0.), (0.25, 0.5)),
+            (Tuple::new_point(d, 0.5, d), (0.375, 0.5)),
+            (Tuple::new_point(0., -0.25, 1.), (0.5, 0.75)),
+            (Tuple::new_point(-d, 0.5, d), (0.625, 0.5)),
+            (Tuple::new_point(-1., 1.25, 0.), (0.75, 0.25)),
+            (Tuple::new_point(-d, 0.5, -d), (0.875, 0.5)),
+        ];
+
+        for (point, uv) in cases.iter() {
+            assert_uv(cylindrical_map(*point), *uv);
+            assert_uv(UvMapping::Cylindrical.map(*point), *uv);
+        }
+    }
+
+    #[test]
+    fn cube_face_from_point() {
+        let cases = [
+            (Tuple::new_point(-1., 0.5, -0.25), CubeFace::Left),
+            (Tuple::new_point(1.1, -0.75, 0.8), CubeFace::Right),
+            (Tuple::new_point(0.1, 0.6, 0.9), CubeFace::Front),
+            (Tuple::new_point(-0.7, 0., -2.), CubeFace::Back),
+            (Tuple::new_point(0.5, 1., 0.9), CubeFace::Up),
+            (Tuple::new_point(-0.2, -1.3, 1.1), CubeFace::Down),
+        ];
+
+        for (point, face) in cases.iter() {
+            assert_eq!(face_from_point(*point), *face);
+        }
+    }
+
+    #[test]
+    fn uv_on_cube_faces() {
+        let cases = [
+            (CubeFace::Front, Tuple::new_point(-0.5, 0.5, 1.), (0.25, 0.75)),
+            (CubeFace::Front, Tuple::new_point(0.5, -0.5, 1.), (0.75, 0.25)),
+            (CubeFace::Back, Tuple::new_point(0.5, 0.5, -1.), (0.25, 0.75)),
+            (CubeFace::Back, Tuple::new_point(-0.5, -0.5, -1.), (0.75, 0.25)),
+            (CubeFace::Left, Tuple::new_point(-1., 0.5, -0.5), (0.25, 0.75)),
+            (CubeFace::Left, Tuple::new_point(-1., -0.5, 0.5), (0.75, 0.25)),
+            (CubeFace::Right, Tuple::new_point(1., 0.5, 0.5), (0.25, 0.75)),
+            (CubeFace::Right, Tuple::new_point(1., -0.5, -0.5), (0.75, 0.25)),
+            (CubeFace::Up, Tuple::new_point(-0.5, 1., -0.5), (0.25, 0.75)),
+            (CubeFace::Up, Tuple::new_point(0.5, 1., 0.5), (0.75, 0.25)),
+            (CubeFace::Down, Tuple::new_point(-0.5, -1., 0.5), (0.25, 0.75)),
+            (CubeFace::Down, Tuple::new_point(0.5, -1., -0.5), (0.75, 0.25)),
+        ];
+
+        for (face, point, uv) in cases.iter() {
+            assert_uv(cube_uv(*face, *point), *uv);
+            assert_uv(UvMapping::Cube.map(*point), *uv);
+        }
+    }
+
+    #[test]
+    fn align_check_corners() {
+        let main = Color::new(1., 1., 1.);
+        let ul = Color::new(1., 0., 0.);
+        let ur = Color::new(1., 1., 0.);
+        let bl = Color::new(0., 1., 0.);
+        let br = Color::new(0., 1., 1.);
+        let pattern = UvPattern::new_align_check(main, ul, ur, bl, br);
+        let cases = [
+            (0.5, 0.5, main),
+            (0.1, 0.9, ul),
+            (0.9, 0.9, ur),
+            (0.1, 0.1, bl),
+            (0.9, 0.1, br),
+        ];
+
+        for (u, v, color) in cases.iter() {
+            assert_eq!(pattern.color_at(*u, *v), *color);
+        }
+    }
+
+    fn image() -> Canvas {
+        let mut image = Canvas::new(2, 2);
+        image.data = vec![
+            vec![Color::new(1., 0., 0.), Color::new(0., 1., 0.)],
+            vec![Color::new(0., 0., 1.), Color::new(1., 1., 1.)],
+        ];
+        image
+    }
+
+    #[test]
+    fn nearest_pixel_of_image() {
+        let pattern = UvPattern::new_image(image(), Filtering::Nearest);
+
+        // v goes up, so the first row of the image is at the top
+        assert_eq!(pattern.color_at(0., 1.), Color::new(1., 0., 0.));
+        assert_eq!(pattern.color_at(1., 1.), Color::new(0., 1., 0.));
+        assert_eq!(pattern.color_at(0., 0.), Color::new(0., 0., 1.));
+        assert_eq!(pattern.color_at(0.9, 0.1), Color::new(1., 1., 1.));
+        assert_eq!(pattern.color_at(0.4, 0.6), Color::new(1., 0., 0.));
+    }
+
+    #[test]
+    fn bilinear_image() {
+        let pattern = UvPattern::new_image(image(), Filtering::Bilinear);
+
+        assert_eq!(pattern.color_at(0., 1.), Color::new(1., 0., 0.));
+        assert_eq!(pattern.color_at(1., 0.), Color::new(1., 1., 1.));
+        assert_eq!(pattern.color_at(0.25, 1.), Color::new(0.75, 0.25, 0.));
+        assert_eq!(pattern.color_at(0.5, 0.5), Color::new(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn image_outside_of_uv_range() {
+        let pattern = UvPattern::new_image(image(), Filtering::Bilinear);
+
+        assert_eq!(pattern.color_at(-1., 2.), Color::new(1., 0., 0.));
+        assert_eq!(pattern.color_at(2., -1.), Color::new(1., 1., 1.));
+    }
+}
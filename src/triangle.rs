@@ -0,0 +1,100 @@
+use super::utils::EPSILON;
+use super::shape::Shape;
+use super::ray::Ray;
+use super::generics::Drawable;
+use super::tuple::Tuple;
+use super::matrix::Matrix4;
+use super::intersection::Intersection;
+use super::bounds::BoundingBox;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangle {
+    pub shape: Shape,
+    pub p1: Tuple,
+    pub p2: Tuple,
+    pub p3: Tuple,
+    pub e1: Tuple,
+    pub e2: Tuple,
+    pub normal: Tuple,
+}
+
+#[allow(dead_code)]
+impl Triangle {
+    pub fn new(p1: Tuple, p2: Tuple, p3: Tuple) -> Self {
+        let shape: Shape = Default::default();
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        let normal = e2.cross(e1).normalize();
+
+        Self {
+            shape,
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal,
+        }
+    }
+
+    pub fn local_intersect_uv(&self, local_ray: Ray) -> Option<(f64, f64, f64)> {
+        intersect_uv(self.p1, self.e1, self.e2, local_ray)
+    }
+}
+
+// Möller–Trumbore. Gives back t together with the u and v
+// barycentric coordinates of where the ray crossed the triangle
+pub fn intersect_uv(p1: Tuple, e1: Tuple, e2: Tuple, ray: Ray) -> Option<(f64, f64, f64)> {
+    let dir_cross_e2 = ray.direction.cross(e2);
+    let det = e1.dot(dir_cross_e2);
+    if det.abs() < EPSILON {
+        return None;
+    }
+
+    let f = 1.0 / det;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * p1_to_origin.dot(dir_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin.cross(e1);
+    let v = f * ray.direction.dot(origin_cross_e1);
+    if v < 0.0 || (u + v) > 1.0 {
+        return None;
+    }
+
+    let t = f * e2.dot(origin_cross_e1);
+    Some((t, u, v))
+}
+
+impl Drawable for Triangle {
+    fn local_intersect(&self, local_ray: Ray) -> Option<Vec<f64>> {
+        let (t, _, _) = self.local_intersect_uv(local_ray)?;
+        Some(vec![t])
+    }
+
+    fn local_normal_at(&self, _: Tuple, _: &Intersection) -> Tuple {
+        self.normal
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+        bounds
+    }
+
+    fn get_transform(&self) -> Matrix4 {
+        self.shape.get_transform()
+    }
+
+    fn get_shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn get_shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
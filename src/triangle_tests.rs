@@ -0,0 +1,97 @@
+#[cfg(test)]
+
+mod triangle_tests {
+    use crate::triangle::*;
+    use crate::tuple::Tuple;
+    use crate::intersection::Intersection;
+    use crate::ray::Ray;
+    use crate::color::Color;
+    use crate::point_light::PointLight;
+    use crate::lights::Lights;
+    use crate::world::World;
+    use crate::generics::{Drawable, Drawables};
+
+    fn default_triangle() -> Triangle {
+        Triangle::new(
+            Tuple::new_point(0., 1., 0.),
+            Tuple::new_point(-1., 0., 0.),
+            Tuple::new_point(1., 0., 0.),
+        )
+    }
+
+    #[test]
+    fn construct_triangle() {
+        let t = default_triangle();
+
+        assert_eq!(t.p1, Tuple::new_point(0., 1., 0.));
+        assert_eq!(t.p2, Tuple::new_point(-1., 0., 0.));
+        assert_eq!(t.p3, Tuple::new_point(1., 0., 0.));
+        assert_eq!(t.e1, Tuple::new_vector(-1., -1., 0.));
+        assert_eq!(t.e2, Tuple::new_vector(1., -1., 0.));
+        assert_eq!(t.normal, Tuple::new_vector(0., 0., -1.));
+    }
+
+    #[test]
+    fn normal_on_triangle() {
+        let t = default_triangle();
+        let d = Drawables::Triangle(t.clone());
+        let i = Intersection::new(0., &d);
+
+        assert_eq!(t.local_normal_at(Tuple::new_point(0., 0.5, 0.), &i), t.normal);
+        assert_eq!(t.local_normal_at(Tuple::new_point(-0.5, 0.75, 0.), &i), t.normal);
+        assert_eq!(t.local_normal_at(Tuple::new_point(0.5, 0.25, 0.), &i), t.normal);
+    }
+
+    #[test]
+    fn intersect_ray_parallel_to_triangle() {
+        let t = default_triangle();
+        let r = Ray::new(Tuple::new_point(0., -1., -2.), Tuple::new_vector(0., 1., 0.)).unwrap();
+
+        assert_eq!(t.local_intersect(r), None);
+    }
+
+    #[test]
+    fn ray_misses_p1_p3_edge() {
+        let t = default_triangle();
+        let r = Ray::new(Tuple::new_point(1., 1., -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+
+        assert_eq!(t.local_intersect(r), None);
+    }
+
+    #[test]
+    fn ray_misses_p1_p2_edge() {
+        let t = default_triangle();
+        let r = Ray::new(Tuple::new_point(-1., 1., -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+
+        assert_eq!(t.local_intersect(r), None);
+    }
+
+    #[test]
+    fn ray_misses_p2_p3_edge() {
+        let t = default_triangle();
+        let r = Ray::new(Tuple::new_point(0., -1., -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+
+        assert_eq!(t.local_intersect(r), None);
+    }
+
+    #[test]
+    fn ray_strikes_triangle() {
+        let t = default_triangle();
+        let r = Ray::new(Tuple::new_point(0., 0.5, -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let xs = t.local_intersect(r).unwrap();
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0], 2.);
+    }
+
+    #[test]
+    fn shade_triangle_in_world() {
+        let w = World {
+            lights: vec![Lights::Point(PointLight::new(Tuple::new_point(0., 0.5, -10.), Color::new(1., 1., 1.)).unwrap())],
+            objects: vec![Drawables::Triangle(default_triangle())],
+        };
+        let r = Ray::new(Tuple::new_point(0., 0.5, -2.), Tuple::new_vector(0., 0., 1.)).unwrap();
+
+        assert_eq!(w.color_at(r, 1), Color::new(1.9, 1.9, 1.9));
+    }
+}
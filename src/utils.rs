@@ -2,7 +2,7 @@
 // like lighting and shit that doesn't clearly belong to a struct
 // We'll see what happens
 use super::color::Color;
-use super::point_light::PointLight;
+use super::lights::{to_light, Light, Lights};
 use super::tuple::Tuple;
 use super::generics::{Drawables, Drawable};
 use super::intersection::Comps;
@@ -15,22 +15,23 @@ pub fn is_equal(a: f64, b: f64) -> bool {
 }
 
 #[allow(dead_code, unused_variables)]
-// Phong lighting
+// Phong lighting. The intensity is how much of the light reaches the point,
+// from 0 when it is in full shadow to 1 when nothing is in the way.
 pub fn lighting(
     object: &Drawables,
     point: Tuple,
-    light: PointLight,
+    light: Lights,
     eye_v: Tuple,
     normal_v: Tuple,
-    in_shadow: bool,
+    intensity: f64,
 ) -> Result<Color, &'static str> {
     if point.is_vector() || eye_v.is_point() || normal_v.is_point() {
         return Err("point or vectors not correct format");
     }
-    let material = object.get_shape().material;
+    let material = &object.get_shape().material;
     let color: Color;
 
-    if let Some(p) = material.pattern {
+    if let Some(p) = &material.pattern {
         match p.color_at_object(object, point) {
             Some(c) => color = c,
             None => return Err("Could not get pattern color at object")
@@ -39,29 +40,38 @@ pub fn lighting(
         color = material.color;
     }
 
-    let effective_color = color * light.intensity;
+    let effective_color = color * light.intensity();
     let ambient = effective_color * material.ambient;
-    if in_shadow {
+    let intensity = intensity * light.falloff(point);
+    if intensity <= 0.0 {
         return Ok(ambient);
     }
 
-    let light_v = (light.position - point).normalize();
+    // Diffuse and specular are averaged over every position of the light
+    let positions = light.positions(point);
     let mut diffuse = Color::new(0.0, 0.0, 0.0);
     let mut specular = Color::new(0.0, 0.0, 0.0);
 
-    let light_dot_normal = light_v.dot(normal_v);
-    if light_dot_normal >= 0.0 {
-        diffuse = effective_color * material.diffuse * light_dot_normal;
+    for position in positions.iter() {
+        let (light_v, _) = to_light(point, *position);
+        let light_dot_normal = light_v.dot(normal_v);
+        if light_dot_normal < 0.0 {
+            continue;
+        }
+
+        diffuse = diffuse + effective_color * material.diffuse * light_dot_normal;
 
         let reflect_v = -light_v.reflect(normal_v);
         let reflect_dot_eye = reflect_v.dot(eye_v);
 
         if reflect_dot_eye >= 0.0 {
             let factor = reflect_dot_eye.powf(material.shininess);
-            specular = light.intensity * material.specular * factor;
+            specular = specular + light.intensity() * material.specular * factor;
         }
     }
-    Ok(ambient + diffuse + specular)
+
+    let samples = positions.len() as f64;
+    Ok(ambient + (diffuse / samples + specular / samples) * intensity)
 }
 
 pub fn schlick(comps: Comps) -> f64 {
@@ -4,6 +4,10 @@ mod utils_tests {
     use crate::utils::*;
     use crate::tuple::Tuple;
     use crate::point_light::PointLight;
+    use crate::lights::Lights;
+    use crate::area_light::AreaLight;
+    use crate::spot_light::SpotLight;
+    use crate::material::Material;
     use crate::color::Color;
     use crate::generics::Drawables;
     use crate::sphere::Sphere;
@@ -19,9 +23,9 @@ mod utils_tests {
         let eye_v = Tuple::new_vector(0., 0., -1.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
 
-        let light = PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap());
 
-        let result = lighting(&sphere, position, light, eye_v, normal_v, false).unwrap();
+        let result = lighting(&sphere, position, light, eye_v, normal_v, 1.).unwrap();
         assert_eq!(result, Color::new(1.9, 1.9, 1.9));
     }
 
@@ -33,9 +37,9 @@ mod utils_tests {
         let eye_v = Tuple::new_vector(0., 2_f64.sqrt()/2., -2_f64.sqrt()/2.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
 
-        let light = PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap());
 
-        let result = lighting(&sphere, position, light, eye_v, normal_v, false).unwrap();
+        let result = lighting(&sphere, position, light, eye_v, normal_v, 1.).unwrap();
         assert_eq!(result, Color::new(1.0, 1.0, 1.0));
     }
 
@@ -47,9 +51,9 @@ mod utils_tests {
         let eye_v = Tuple::new_vector(0., 0., -1.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
 
-        let light = PointLight::new(Tuple::new_point(0., 10., -10.), Color::new(1., 1., 1.)).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 10., -10.), Color::new(1., 1., 1.)).unwrap());
 
-        let result = lighting(&sphere, position, light, eye_v, normal_v, false).unwrap();
+        let result = lighting(&sphere, position, light, eye_v, normal_v, 1.).unwrap();
         assert_eq!(result, Color::new(0.7364, 0.7364, 0.7364));
     }
 
@@ -61,9 +65,9 @@ mod utils_tests {
         let eye_v = Tuple::new_vector(0., -2_f64.sqrt()/2., -2_f64.sqrt()/2.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
 
-        let light = PointLight::new(Tuple::new_point(0., 10., -10.), Color::new(1., 1., 1.)).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 10., -10.), Color::new(1., 1., 1.)).unwrap());
 
-        let result = lighting(&sphere, position, light, eye_v, normal_v, false).unwrap();
+        let result = lighting(&sphere, position, light, eye_v, normal_v, 1.).unwrap();
         assert_eq!(result, Color::new(1.6364, 1.6364, 1.6364));
     }
 
@@ -75,9 +79,9 @@ mod utils_tests {
         let eye_v = Tuple::new_vector(0., 0., -1.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
 
-        let light = PointLight::new(Tuple::new_point(0., 0., 10.), Color::new(1., 1., 1.)).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., 10.), Color::new(1., 1., 1.)).unwrap());
 
-        let result = lighting(&sphere, position, light, eye_v, normal_v, false).unwrap();
+        let result = lighting(&sphere, position, light, eye_v, normal_v, 1.).unwrap();
         assert_eq!(result, Color::new(0.1, 0.1, 0.1));
     }
 
@@ -89,10 +93,86 @@ mod utils_tests {
         let eye_v = Tuple::new_vector(0., 0., -1.);
         let normal_v = Tuple::new_vector(0., 0., -1.);
 
-        let light = PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap();
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap());
 
-        let in_shadow = true;
-        let result = lighting(&sphere, position, light, eye_v, normal_v, in_shadow).unwrap();
+        let intensity = 0.;
+        let result = lighting(&sphere, position, light, eye_v, normal_v, intensity).unwrap();
+        assert_eq!(result, Color::new(0.1, 0.1, 0.1));
+    }
+
+    #[test]
+    fn lighting_uses_light_intensity() {
+        let mut sphere = Sphere::new();
+        sphere.shape.material = Material {
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.,
+            color: Color::new(1., 1., 1.),
+            ..Default::default()
+        };
+        let sphere = Drawables::Sphere(sphere);
+        let light = Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap());
+        let position = Tuple::new_point(0., 0., -1.);
+        let eye_v = Tuple::new_vector(0., 0., -1.);
+        let normal_v = Tuple::new_vector(0., 0., -1.);
+
+        let cases = [(1., 1.), (0.5, 0.55), (0., 0.1)];
+        for (intensity, result) in cases.iter() {
+            let c = lighting(&sphere, position, light, eye_v, normal_v, *intensity).unwrap();
+            assert_eq!(c, Color::new(*result, *result, *result));
+        }
+    }
+
+    #[test]
+    fn lighting_samples_area_light() {
+        let light = Lights::Area(AreaLight::new(
+            Tuple::new_point(-0.5, -0.5, -5.),
+            Tuple::new_vector(1., 0., 0.),
+            2,
+            Tuple::new_vector(0., 1., 0.),
+            2,
+            Color::new(1., 1., 1.),
+        ).unwrap());
+        let mut sphere = Sphere::new();
+        sphere.shape.material = Material {
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.,
+            color: Color::new(1., 1., 1.),
+            ..Default::default()
+        };
+        let sphere = Drawables::Sphere(sphere);
+        let eye = Tuple::new_point(0., 0., -5.);
+
+        let cases = [
+            (Tuple::new_point(0., 0., -1.), Color::new(0.9965, 0.9965, 0.9965)),
+            (Tuple::new_point(0., 2_f64.sqrt() / 2., -2_f64.sqrt() / 2.), Color::new(0.62318, 0.62318, 0.62318)),
+        ];
+        for (point, result) in cases.iter() {
+            let eye_v = (eye - *point).normalize();
+            let normal_v = Tuple::new_vector(point.x, point.y, point.z);
+            assert_eq!(lighting(&sphere, *point, light, eye_v, normal_v, 1.).unwrap(), *result);
+        }
+    }
+
+    #[test]
+    fn lighting_outside_of_spot_light() {
+        let sphere = Drawables::Sphere(Sphere::new());
+        let eye_v = Tuple::new_vector(0., 0., -1.);
+        let normal_v = Tuple::new_vector(0., 0., -1.);
+        let light = Lights::Spot(SpotLight::new(
+            Tuple::new_point(0., 0., -10.),
+            Tuple::new_vector(0., 0., 1.),
+            0.1,
+            0.2,
+            Color::new(1., 1., 1.),
+        ).unwrap());
+
+        // Straight down the cone it acts like a point light,
+        // off to the side only the ambient part is left
+        let result = lighting(&sphere, Tuple::new_point(0., 0., 0.), light, eye_v, normal_v, 1.).unwrap();
+        assert_eq!(result, Color::new(1.9, 1.9, 1.9));
+        let result = lighting(&sphere, Tuple::new_point(0., 5., 0.), light, eye_v, normal_v, 1.).unwrap();
         assert_eq!(result, Color::new(0.1, 0.1, 0.1));
     }
 
@@ -1,4 +1,5 @@
 use super::point_light::PointLight;
+use super::lights::{to_light, Light, Lights};
 use super::sphere::Sphere;
 use super::material::Material;
 use super::color::Color;
@@ -9,20 +10,37 @@ use super::ray::Ray;
 use super::utils::{lighting, schlick};
 use super::intersection::hit;
 use super::generics::{Drawables, Drawable};
+use super::group::Group;
+
+// How many children a group can have before World::divide splits it up
+pub const DIVIDE_THRESHOLD: usize = 8;
 
 #[allow(dead_code)]
 pub struct World {
-    pub lights: Vec<PointLight>,
+    pub lights: Vec<Lights>,
     pub objects: Vec<Drawables>,
 }
 
 #[allow(dead_code)]
 impl World {
+    // Sorts the objects into a bounding volume hierarchy, so that rays only
+    // have to be tested against the objects that are close to them.
+    // Unbounded objects like planes stay where they are.
+    pub fn divide(&mut self, threshold: usize) {
+        let mut root = Group::new();
+        for object in self.objects.drain(..) {
+            root.add_child(object);
+        }
+
+        root.divide(threshold);
+        self.objects = root.into_children();
+    }
+
     pub fn shade_hit(&self, comps: Comps, remaining: usize) -> Color {
         let mut color = Color::new(0., 0., 0.);
 
         for light in self.lights.iter() {
-            let in_shadow = self.is_shadowed(comps.over_point, light);
+            let intensity = self.intensity_at(comps.over_point, light);
 
             if let Ok(result) = lighting(
                 comps.object,
@@ -30,7 +48,7 @@ impl World {
                 *light,
                 comps.eye_v,
                 comps.normal_v,
-                in_shadow
+                intensity
             ) {
                 color = color + result;
 
@@ -46,7 +64,7 @@ impl World {
             Err(_) => Color::new(0., 0., 0.)
         };
 
-        let material = comps.object.get_shape().material;
+        let material = &comps.object.get_shape().material;
         if material.reflective > 0.0 && material.transparency > 0.0 {
             let reflectance = schlick(comps);
             color = color + (reflected * reflectance) + (refracted * (1.0 - reflectance));
@@ -67,10 +85,17 @@ impl World {
         Color::new(0., 0., 0.)
     }
 
-    pub fn is_shadowed(&self, point: Tuple, light: &PointLight) -> bool {
-        let v = light.position - point;
-        let distance = v.magnitude();
-        let direction = v.normalize();
+    // How much of the light reaches the point, from 0 to 1
+    pub fn intensity_at(&self, point: Tuple, light: &Lights) -> f64 {
+        let positions = light.positions(point);
+        let visible = positions.iter().filter(|p| !self.is_shadowed(point, **p)).count();
+        visible as f64 / positions.len() as f64
+    }
+
+    // The light position can also be a vector pointing towards a light that
+    // is infinitely far away, then anything in that direction casts a shadow
+    pub fn is_shadowed(&self, point: Tuple, light_position: Tuple) -> bool {
+        let (direction, distance) = to_light(point, light_position);
         if let Ok(shadow_ray) = Ray::new(point, direction) {
             let xs = shadow_ray.intersect_world(&self);
             if let Some(hit) = hit(&xs) {
@@ -87,7 +112,7 @@ impl World {
             return Ok(Color::new(0., 0., 0.));
         }
 
-        let material = comps.object.get_shape().material;
+        let material = &comps.object.get_shape().material;
         if material.reflective == 0.0 {
             return Ok(Color::new(0., 0., 0.));
         }
@@ -103,7 +128,7 @@ impl World {
             return Ok(Color::new(0., 0., 0.));
         }
 
-        let material = comps.object.get_shape().material;
+        let material = &comps.object.get_shape().material;
         if material.transparency == 0.0 {
             return Ok(Color::new(0., 0., 0.));
         }
@@ -139,6 +164,6 @@ impl Default for World {
 
         let objects = vec!(Drawables::Sphere(s1), Drawables::Sphere(s2));
 
-        Self {lights: vec![light], objects}
+        Self {lights: vec![Lights::Point(light)], objects}
     }
 }
@@ -3,6 +3,9 @@
 mod world_tests {
     use crate::world::World;
     use crate::point_light::PointLight;
+    use crate::lights::Lights;
+    use crate::area_light::AreaLight;
+    use crate::directional_light::DirectionalLight;
     use crate::tuple::Tuple;
     use crate::color::Color;
     use crate::sphere::Sphere;
@@ -29,7 +32,7 @@ mod world_tests {
         let s2 = Sphere::new_with_transform(Matrix4::new_scaling(0.5, 0.5, 0.5));
         let w: World = Default::default();
 
-        assert_eq!(w.lights[0], light);
+        assert_eq!(w.lights[0], Lights::Point(light));
         assert_eq!(w.objects.contains(&Drawables::Sphere(s1)), true);
         assert_eq!(w.objects.contains(&Drawables::Sphere(s2)), true);
     }
@@ -39,10 +42,11 @@ mod world_tests {
         let w: World = Default::default();
         let r = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
 
-        let shape = w.objects[0];
+        let shape = w.objects[0].clone();
         let i = Intersection {
             t: 4.0,
-            object: &shape
+            object: &shape,
+            uv: None
         };
 
         let comps = i.prepare_computations(r, None).unwrap();
@@ -53,16 +57,17 @@ mod world_tests {
     #[test]
     fn shade_intersection_inside() {
         let w = World{
-            lights: vec![PointLight::new(Tuple::new_point(0., 0.25, 0.), Color::new(1., 1., 1.)).unwrap()],
+            lights: vec![Lights::Point(PointLight::new(Tuple::new_point(0., 0.25, 0.), Color::new(1., 1., 1.)).unwrap())],
             ..Default::default()
         };
 
         let r = Ray::new(Tuple::new_point(0., 0., 0.), Tuple::new_vector(0., 0., 1.)).unwrap();
 
-        let shape = w.objects[1];
+        let shape = w.objects[1].clone();
         let i = Intersection {
             t: 0.5,
-            object: &shape
+            object: &shape,
+            uv: None
         };
 
         let comps = i.prepare_computations(r, None).unwrap();
@@ -96,14 +101,14 @@ mod world_tests {
         };
 
         let mut outer = Sphere::new();
-        outer.shape.material = material;
+        outer.shape.material = material.clone();
 
         let mut inner = Sphere::new();
         inner.shape.material = material;
 
 
         let w = World {
-            objects: vec![Drawables::Sphere(outer), Drawables::Sphere(inner)],
+            objects: vec![Drawables::Sphere(outer), Drawables::Sphere(inner.clone())],
             ..Default::default()
         };
 
@@ -116,33 +121,104 @@ mod world_tests {
     #[test]
     fn no_shadow() {
         let w: World = Default::default();
+        let light_position = Tuple::new_point(-10., 10., -10.);
         let p = Tuple::new_point(0., 10., 0.);
 
-        assert_eq!(w.is_shadowed(p, &w.lights[0]), false);
+        assert_eq!(w.is_shadowed(p, light_position), false);
     }
 
     #[test]
     fn shadow_object_between_point_and_light() {
         let w: World = Default::default();
+        let light_position = Tuple::new_point(-10., 10., -10.);
         let p = Tuple::new_point(10., -10., 10.);
 
-        assert_eq!(w.is_shadowed(p, &w.lights[0]), true);
+        assert_eq!(w.is_shadowed(p, light_position), true);
     }
 
     #[test]
     fn no_shadow_object_behing_light() {
         let w: World = Default::default();
+        let light_position = Tuple::new_point(-10., 10., -10.);
         let p = Tuple::new_point(-20., 20., -20.);
 
-        assert_eq!(w.is_shadowed(p, &w.lights[0]), false);
+        assert_eq!(w.is_shadowed(p, light_position), false);
     }
 
     #[test]
     fn no_shadow_object_behind_point() {
         let w: World = Default::default();
+        let light_position = Tuple::new_point(-10., 10., -10.);
         let p = Tuple::new_point(-2., 2., -2.);
 
-        assert_eq!(w.is_shadowed(p, &w.lights[0]), false);
+        assert_eq!(w.is_shadowed(p, light_position), false);
+    }
+
+    #[test]
+    fn shadow_from_light_at_infinity() {
+        let w: World = Default::default();
+        let towards_light = Tuple::new_vector(0., 1., 0.);
+
+        // There is no distance to hide behind, anything above the point casts a shadow
+        assert!(w.is_shadowed(Tuple::new_point(0., -1000., 0.), towards_light));
+        assert!(!w.is_shadowed(Tuple::new_point(0., 1.0001, 0.), towards_light));
+        assert!(!w.is_shadowed(Tuple::new_point(1.5, -1000., 0.), towards_light));
+    }
+
+    #[test]
+    fn shade_hit_with_directional_light() {
+        let w = World {
+            lights: vec![Lights::Directional(DirectionalLight::new(Tuple::new_vector(0., 0., 1.), Color::new(1., 1., 1.)).unwrap())],
+            objects: vec![Drawables::Sphere(Sphere::new())],
+        };
+
+        // Lit straight on, however far away the camera is
+        let near = Ray::new(Tuple::new_point(0., 0., -5.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        let far = Ray::new(Tuple::new_point(0., 0., -5000.), Tuple::new_vector(0., 0., 1.)).unwrap();
+        assert_eq!(w.color_at(near, 1), Color::new(1.9, 1.9, 1.9));
+        assert_eq!(w.color_at(far, 1), Color::new(1.9, 1.9, 1.9));
+    }
+
+    #[test]
+    fn point_light_intensity_at() {
+        let w: World = Default::default();
+        let cases = [
+            (Tuple::new_point(0., 1.0001, 0.), 1.),
+            (Tuple::new_point(-1.0001, 0., 0.), 1.),
+            (Tuple::new_point(0., 0., -1.0001), 1.),
+            (Tuple::new_point(0., 0., 1.0001), 0.),
+            (Tuple::new_point(1.0001, 0., 0.), 0.),
+            (Tuple::new_point(0., -1.0001, 0.), 0.),
+            (Tuple::new_point(0., 0., 0.), 0.),
+        ];
+
+        for (point, intensity) in cases.iter() {
+            assert_eq!(w.intensity_at(*point, &w.lights[0]), *intensity);
+        }
+    }
+
+    #[test]
+    fn area_light_intensity_at() {
+        let w: World = Default::default();
+        let light = Lights::Area(AreaLight::new(
+            Tuple::new_point(-0.5, -0.5, -5.),
+            Tuple::new_vector(1., 0., 0.),
+            2,
+            Tuple::new_vector(0., 1., 0.),
+            2,
+            Color::new(1., 1., 1.),
+        ).unwrap());
+        let cases = [
+            (Tuple::new_point(0., 0., 2.), 0.),
+            (Tuple::new_point(1., -1., 2.), 0.25),
+            (Tuple::new_point(1.5, 0., 2.), 0.5),
+            (Tuple::new_point(1.25, 1.25, 3.), 0.75),
+            (Tuple::new_point(0., 0., -2.), 1.),
+        ];
+
+        for (point, intensity) in cases.iter() {
+            assert_eq!(w.intensity_at(*point, &light), *intensity);
+        }
     }
 
     #[test]
@@ -150,8 +226,8 @@ mod world_tests {
         let s1 = Drawables::Sphere(Sphere::new());
         let s2 = Drawables::Sphere(Sphere::new_with_transform(Matrix4::new_translation(0., 0., 10.)));
         let w = World {
-            lights: vec![PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap()],
-            objects: vec![s1, s2]
+            lights: vec![Lights::Point(PointLight::new(Tuple::new_point(0., 0., -10.), Color::new(1., 1., 1.)).unwrap())],
+            objects: vec![s1, s2.clone()]
         };
 
         let r = Ray::new(Tuple::new_point(0., 0., 5.), Tuple::new_vector(0., 0., 1.)).unwrap();
@@ -179,7 +255,7 @@ mod world_tests {
     fn color_of_non_reflective_mat() {
         let w: World = Default::default();
         let r = Ray::new(Tuple::new_point(0., 0., 0.), Tuple::new_vector(0., 0., 1.)).unwrap();
-        let mut sphere = w.objects[0];
+        let mut sphere = w.objects[0].clone();
 
         let i = Intersection::new(1., &sphere);
 
@@ -199,7 +275,7 @@ mod world_tests {
         };
         let mut shape = Drawables::Plane(plane);
 
-        w.objects.push(shape);
+        w.objects.push(shape.clone());
 
         let r = Ray::new(
             Tuple::new_point(0., 0., -3.),
@@ -223,7 +299,7 @@ mod world_tests {
         };
         let mut shape = Drawables::Plane(plane);
 
-        w.objects.push(shape);
+        w.objects.push(shape.clone());
 
         let r = Ray::new(
             Tuple::new_point(0., 0., -3.),
@@ -241,7 +317,7 @@ mod world_tests {
         let mut w: World = Default::default();
 
         let light = PointLight::new(Tuple::new_point(0., 0., 0.), Color::new(1., 1., 1.)).unwrap();
-        w.lights = vec![light];
+        w.lights = vec![Lights::Point(light)];
 
         let mut lower = Plane::new_with_transform(Matrix4::new_translation(0., -1., 0.));
         lower.shape.material = Material {
@@ -278,7 +354,7 @@ mod world_tests {
         };
         let mut shape = Drawables::Plane(plane);
 
-        w.objects.push(shape);
+        w.objects.push(shape.clone());
 
         let r = Ray::new(
             Tuple::new_point(0., 0., -3.),
@@ -296,17 +372,17 @@ mod world_tests {
         let mut a = Sphere::new();
         a.shape.material.transparency = 1.0;
         a.shape.material.refractive_index = 1.5;
-        a.shape.transform = Matrix4::new_scaling(2., 2., 2.);
+        a.shape.set_transform(Matrix4::new_scaling(2., 2., 2.));
 
         let mut b = Sphere::new();
         b.shape.material.transparency = 1.0;
         b.shape.material.refractive_index = 2.0;
-        b.shape.transform = Matrix4::new_translation(0., 0., -0.25);
+        b.shape.set_transform(Matrix4::new_translation(0., 0., -0.25));
 
         let mut c = Sphere::new();
         c.shape.material.transparency = 1.0;
         c.shape.material.refractive_index = 2.5;
-        c.shape.transform = Matrix4::new_translation(0., 0., 0.25);
+        c.shape.set_transform(Matrix4::new_translation(0., 0., 0.25));
 
         let s1 = Drawables::Sphere(a);
         let s2 = Drawables::Sphere(b);
@@ -340,7 +416,7 @@ mod world_tests {
     fn refracted_color_with_opaque_surface() {
         let mut w: World = Default::default();
 
-        let shape = w.objects[0];
+        let shape = w.objects[0].clone();
 
         let r = Ray::new(
             Tuple::new_point(0., 0., -5.),
@@ -363,8 +439,8 @@ mod world_tests {
     fn refracted_color_max_recursion() {
         let mut w: World = Default::default();
 
-        let mut shape = w.objects[0];
-        if let Drawables::Sphere(mut s) = shape {
+        let mut shape = w.objects[0].clone();
+        if let Drawables::Sphere(mut s) = shape.clone() {
             s.shape.material.transparency = 1.0;
             s.shape.material.refractive_index = 1.5;
         }
@@ -389,8 +465,8 @@ mod world_tests {
     fn refracted_color_total_internal_reflection() {
         let mut w: World = Default::default();
 
-        let mut shape = w.objects[0];
-        if let Drawables::Sphere(mut s) = shape {
+        let mut shape = w.objects[0].clone();
+        if let Drawables::Sphere(mut s) = shape.clone() {
             s.shape.material.transparency = 1.0;
             s.shape.material.refractive_index = 1.5;
             shape = Drawables::Sphere(s);
@@ -416,21 +492,21 @@ mod world_tests {
     fn refracted_color_with_refracted_ray() {
         let mut w: World = Default::default();
 
-        let mut o1 = w.objects[0];
+        let mut o1 = w.objects[0].clone();
         if let Drawables::Sphere(mut s) = o1 {
             s.shape.material.ambient = 1.0;
             s.shape.material.pattern = Some(Patterns::new_test());
             w.objects[0] = Drawables::Sphere(s);
         }
-        let s1 = w.objects[0];
+        let s1 = w.objects[0].clone();
 
-        let mut o2 = w.objects[1];
+        let mut o2 = w.objects[1].clone();
         if let Drawables::Sphere(mut s) = o2 {
             s.shape.material.transparency = 1.0;
             s.shape.material.refractive_index = 1.5;
             w.objects[1] = Drawables::Sphere(s);
         }
-        let s2 = w.objects[1];
+        let s2 = w.objects[1].clone();
 
         let r = Ray::new(
             Tuple::new_point(0., 0., 0.1),
@@ -509,4 +585,41 @@ mod world_tests {
 
         assert_eq!(color, Color::new(0.93391, 0.69643, 0.69243));
     }
+
+    #[test]
+    fn divided_world_looks_the_same() {
+        let mut objects = vec![Drawables::Plane(Plane::new_with_transform(Matrix4::new_translation(0., -1., 0.)))];
+        for i in 0..5 {
+            for j in 0..5 {
+                let transform = Matrix4::new_translation(i as f64 * 0.6 - 1.2, j as f64 * 0.6 - 1.2, 0.)
+                    * Matrix4::new_scaling(0.25, 0.25, 0.25);
+                objects.push(Drawables::Sphere(Sphere::new_with_transform(transform)));
+            }
+        }
+
+        let w = World {
+            objects: objects.clone(),
+            ..Default::default()
+        };
+        let mut divided = World {
+            objects,
+            ..Default::default()
+        };
+        divided.divide(4);
+
+        assert!(divided.objects.len() < w.objects.len());
+
+        for i in 0..10 {
+            for j in 0..10 {
+                let target = Tuple::new_point(i as f64 * 0.3 - 1.5, j as f64 * 0.3 - 1.5, 0.);
+                let origin = Tuple::new_point(0.3, 0.2, -5.);
+                let r = Ray::new(origin, (target - origin).normalize()).unwrap();
+
+                let ts: Vec<f64> = r.intersect_world(&w).iter().map(|i| i.t).collect();
+                let divided_ts: Vec<f64> = r.intersect_world(&divided).iter().map(|i| i.t).collect();
+                assert_eq!(ts, divided_ts);
+                assert_eq!(w.color_at(r, 1), divided.color_at(r, 1));
+            }
+        }
+    }
 }
@@ -0,0 +1,142 @@
+use yaml_rust::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust::scanner::Marker;
+
+// A parsed yaml document where every value remembers the line it came from,
+// so that whatever reads it can point at the problem when something is wrong
+#[derive(Debug, Clone, PartialEq)]
+pub struct YamlNode {
+    pub value: YamlValue,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum YamlValue {
+    Scalar(String),
+    Sequence(Vec<YamlNode>),
+    // Kept as a list so that the keys stay in the order they were written
+    Mapping(Vec<(YamlNode, YamlNode)>),
+}
+
+#[allow(dead_code)]
+impl YamlNode {
+    // Returns None for an empty document
+    pub fn parse(input: &str) -> Result<Option<Self>, String> {
+        let mut builder = Builder {
+            stack: vec![],
+            root: None,
+            error: None,
+        };
+
+        let mut parser = Parser::new(input.chars());
+        if let Err(e) = parser.load(&mut builder, false) {
+            return Err(format!("line {}: {}", e.marker().line(), e));
+        }
+
+        match builder.error {
+            Some(error) => Err(error),
+            None => Ok(builder.root),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            YamlValue::Scalar(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        self.as_str()?.parse::<f64>().ok()
+    }
+
+    pub fn as_sequence(&self) -> Option<&Vec<YamlNode>> {
+        match &self.value {
+            YamlValue::Sequence(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_mapping(&self) -> Option<&Vec<(YamlNode, YamlNode)>> {
+        match &self.value {
+            YamlValue::Mapping(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&YamlNode> {
+        self.as_mapping()?
+            .iter()
+            .find(|(k, _)| k.as_str() == Some(key))
+            .map(|(_, v)| v)
+    }
+}
+
+// A sequence or mapping that is still being read
+struct Frame {
+    value: YamlValue,
+    line: usize,
+    key: Option<YamlNode>,
+}
+
+struct Builder {
+    stack: Vec<Frame>,
+    root: Option<YamlNode>,
+    error: Option<String>,
+}
+
+impl Builder {
+    fn insert(&mut self, node: YamlNode) {
+        let frame = match self.stack.last_mut() {
+            Some(frame) => frame,
+            None => {
+                self.root = Some(node);
+                return;
+            }
+        };
+
+        match &mut frame.value {
+            YamlValue::Sequence(items) => items.push(node),
+            YamlValue::Mapping(entries) => match frame.key.take() {
+                Some(key) => entries.push((key, node)),
+                None => frame.key = Some(node),
+            },
+            YamlValue::Scalar(_) => (),
+        }
+    }
+
+    fn close(&mut self) {
+        if let Some(frame) = self.stack.pop() {
+            self.insert(YamlNode {
+                value: frame.value,
+                line: frame.line,
+            });
+        }
+    }
+}
+
+impl MarkedEventReceiver for Builder {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        match event {
+            Event::Scalar(value, _, _, _) => self.insert(YamlNode {
+                value: YamlValue::Scalar(value),
+                line: mark.line(),
+            }),
+            Event::SequenceStart(_) => self.stack.push(Frame {
+                value: YamlValue::Sequence(vec![]),
+                line: mark.line(),
+                key: None,
+            }),
+            Event::MappingStart(_) => self.stack.push(Frame {
+                value: YamlValue::Mapping(vec![]),
+                line: mark.line(),
+                key: None,
+            }),
+            Event::SequenceEnd | Event::MappingEnd => self.close(),
+            // We'd need to keep every anchored node around to support these
+            Event::Alias(_) if self.error.is_none() => {
+                self.error = Some(format!("line {}: aliases are not supported", mark.line()));
+            },
+            _ => (),
+        }
+    }
+}
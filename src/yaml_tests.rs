@@ -0,0 +1,60 @@
+#[cfg(test)]
+
+mod yaml_tests {
+    use crate::yaml::*;
+
+    #[test]
+    fn parse_nested_values() {
+        let input = "- add: sphere
+  transform:
+    - [scale, 1, 2, 3]
+";
+        let root = YamlNode::parse(input).unwrap().unwrap();
+        let entry = &root.as_sequence().unwrap()[0];
+
+        assert_eq!(entry.get("add").unwrap().as_str(), Some("sphere"));
+        let step = &entry.get("transform").unwrap().as_sequence().unwrap()[0];
+        assert_eq!(step.as_sequence().unwrap()[2].as_f64(), Some(2.));
+    }
+
+    #[test]
+    fn nodes_know_their_line() {
+        let input = "# a comment
+
+- add: sphere
+  material:
+    color: [1, 0, 0]
+";
+        let root = YamlNode::parse(input).unwrap().unwrap();
+        let entry = &root.as_sequence().unwrap()[0];
+
+        assert_eq!(entry.line, 3);
+        assert_eq!(entry.get("material").unwrap().line, 5);
+        assert_eq!(entry.get("material").unwrap().get("color").unwrap().line, 5);
+    }
+
+    #[test]
+    fn keys_keep_their_order() {
+        let root = YamlNode::parse("{b: 1, a: 2, c: 3}").unwrap().unwrap();
+        let keys: Vec<&str> = root.as_mapping().unwrap().iter().map(|(k, _)| k.as_str().unwrap()).collect();
+
+        assert_eq!(keys, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn empty_document() {
+        assert_eq!(YamlNode::parse(""), Ok(None));
+    }
+
+    #[test]
+    fn invalid_yaml() {
+        let e = YamlNode::parse("- [1, 2\n- 3").unwrap_err();
+        assert!(e.starts_with("line 3:"), "{}", e);
+    }
+
+    #[test]
+    fn aliases_are_rejected() {
+        let e = YamlNode::parse("- &a 1\n- *a").unwrap_err();
+        assert_eq!(e, "line 2: aliases are not supported");
+    }
+}